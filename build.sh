@@ -11,7 +11,7 @@ export EMCC_CFLAGS="-O3
                     -o ./wasm/uroborosql-fmt.js
                     -s ALLOW_MEMORY_GROWTH=1
                     -s STACK_SIZE=5MB
-                    -s EXPORTED_FUNCTIONS=['_format_sql','_get_result_address','_get_error_msg_address']
+                    -s EXPORTED_FUNCTIONS=['_format_sql','_get_result_address','_get_error_msg_address','_get_warnings_address']
                     -s EXPORTED_RUNTIME_METHODS=ccall"
 # 全体のビルドを実行
 cargo build --package uroborosql-fmt-wasm --target wasm32-unknown-emscripten --release
@@ -0,0 +1,159 @@
+//! フォーマット済みのファイルの内容のハッシュ値を保存し、次回以降の実行で内容が変わっていないファイルのフォーマットを省略する。
+//!
+//! キャッシュファイルは以下の形式のテキストファイルである。
+//! 1行目のキーが現在の実行ファイルから計算したキーと一致しない場合、キャッシュは破棄される。
+//! ハッシュ値はファイルに適用される設定と、フォーマット済みの内容から計算する。
+//!
+//! ```text
+//! uroborosql-fmt-cache <キー>
+//! <ハッシュ値>\t<ファイルのパス>
+//! ...
+//! ```
+
+use std::collections::HashMap;
+use std::fs::{read_to_string, File};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+
+/// キャッシュファイルのヘッダ
+const HEADER: &str = "uroborosql-fmt-cache";
+
+/// フォーマット済みのファイルの記録
+pub(crate) struct Cache {
+    /// キャッシュファイルのパス
+    path: PathBuf,
+    /// 実行ファイルから計算したキー
+    key: u64,
+    /// ファイルのパスと、設定およびフォーマット済みの内容のハッシュ値
+    entries: HashMap<String, u64>,
+}
+
+impl Cache {
+    /// キャッシュファイルを読み込む。
+    /// ファイルが存在しない場合やキーが一致しない場合は、空のキャッシュを返す。
+    pub(crate) fn load(path: impl Into<PathBuf>, key: u64) -> Cache {
+        let path = path.into();
+        let entries = read_to_string(&path)
+            .ok()
+            .and_then(|content| parse_entries(&content, key))
+            .unwrap_or_default();
+
+        Cache { path, key, entries }
+    }
+
+    /// ファイルの内容が、同じ設定でフォーマット済みとして記録されている場合 true を返す
+    pub(crate) fn is_formatted(&self, file: &str, config: &str, content: &str) -> bool {
+        self.entries.get(file) == Some(&entry_hash(config, content))
+    }
+
+    /// ファイルの内容を、設定とともにフォーマット済みとして記録する
+    pub(crate) fn insert(&mut self, file: &str, config: &str, content: &str) {
+        self.entries
+            .insert(file.to_string(), entry_hash(config, content));
+    }
+
+    /// キャッシュファイルに書き込む
+    pub(crate) fn save(&self) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(&self.path)?);
+
+        writeln!(writer, "{HEADER} {:016x}", self.key)?;
+        for (file, hash) in &self.entries {
+            writeln!(writer, "{hash:016x}\t{file}")?;
+        }
+
+        writer.flush()
+    }
+}
+
+/// キャッシュファイルの内容を解析する。
+/// キーが一致しない場合や形式が正しくない場合は None を返す。
+fn parse_entries(content: &str, key: u64) -> Option<HashMap<String, u64>> {
+    let mut lines = content.lines();
+
+    let header = lines.next()?.strip_prefix(HEADER)?.trim();
+    if u64::from_str_radix(header, 16).ok()? != key {
+        return None;
+    }
+
+    lines
+        .map(|line| {
+            let (hash, file) = line.split_once('\t')?;
+            Some((file.to_string(), u64::from_str_radix(hash, 16).ok()?))
+        })
+        .collect()
+}
+
+/// 設定とファイルの内容から、キャッシュに記録するハッシュ値を返す
+fn entry_hash(config: &str, content: &str) -> u64 {
+    hash_from(hash(config.as_bytes()), content.as_bytes())
+}
+
+/// バイト列のハッシュ値 (FNV-1a) を返す。
+/// キャッシュファイルに保存するため、Rust のバージョンによらず同じ値となるハッシュ関数を使用する。
+pub(crate) fn hash(data: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+
+    hash_from(OFFSET_BASIS, data)
+}
+
+/// ハッシュ値 state にバイト列を追加したハッシュ値を返す
+fn hash_from(state: u64, data: &[u8]) -> u64 {
+    const PRIME: u64 = 0x100000001b3;
+
+    data.iter()
+        .fold(state, |acc, &b| (acc ^ u64::from(b)).wrapping_mul(PRIME))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use super::{parse_entries, Cache, HEADER};
+
+    /// テストごとのキャッシュファイルのパスを返す
+    fn cache_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "uroborosql-fmt-cache-{name}-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn parse_cache_file() {
+        let content = format!("{HEADER} 000000000000002a\n00000000000000ff\ta.sql\n");
+
+        let entries = parse_entries(&content, 42).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries["a.sql"], 0xff);
+
+        // キーが一致しない場合や、形式が正しくない場合は破棄する
+        assert!(parse_entries(&content, 43).is_none());
+        assert!(parse_entries("other-cache 000000000000002a\n", 42).is_none());
+        assert!(parse_entries(&format!("{HEADER} 000000000000002a\nxyz\ta.sql\n"), 42).is_none());
+        assert!(parse_entries(&format!("{HEADER} 000000000000002a\nff a.sql\n"), 42).is_none());
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = cache_path("save");
+
+        let mut cache = Cache::load(&path, 1);
+        assert!(!cache.is_formatted("a.sql", "{}", "select"));
+        cache.insert("a.sql", "{}", "select");
+        cache.save().unwrap();
+
+        let cache = Cache::load(&path, 1);
+        assert!(cache.is_formatted("a.sql", "{}", "select"));
+        // 内容や設定が変わった場合は、フォーマット済みとみなさない
+        assert!(!cache.is_formatted("a.sql", "{}", "select a"));
+        assert!(!cache.is_formatted("a.sql", r#"{"tab_size":2}"#, "select"));
+        assert!(!cache.is_formatted("b.sql", "{}", "select"));
+
+        // 実行ファイルが変わった (キーが異なる) 場合は、キャッシュを破棄する
+        let cache = Cache::load(&path, 2);
+        assert!(!cache.is_formatted("a.sql", "{}", "select"));
+    }
+}
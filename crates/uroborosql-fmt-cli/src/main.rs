@@ -1,28 +1,214 @@
-use std::fs::read_to_string;
-use std::fs::File;
-use std::io::Write;
-use std::path::Path;
+mod cache;
 
-use uroborosql_fmt::format_sql;
+use std::fs::{self, read_to_string, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
-fn main() {
-    let msg = "arguments error";
-    let input_file = std::env::args().nth(1).expect(msg);
+use cache::Cache;
+use uroborosql_fmt::{
+    config::{json_schema, Config},
+    format_sql_with_report,
+    warning::Warning,
+};
 
-    let output_file = std::env::args().nth(2);
+/// フォーマットが必要なファイルが存在した場合の終了コード
+const EXIT_NEEDS_FORMAT: u8 = 1;
+/// 引数の誤りやフォーマットの失敗があった場合の終了コード
+const EXIT_ERROR: u8 = 2;
 
-    let src = read_to_string(input_file).unwrap();
+/// UTF-8 の BOM
+const BOM: char = '\u{feff}';
 
-    let config_path = match Path::is_file(Path::new("./.uroborosqlfmtrc.json")) {
-        true => Some("./.uroborosqlfmtrc.json"),
-        false => {
-            eprintln!("hint: Create the file '.uroborosqlfmtrc.json' if you want to customize the configuration");
+/// 設定ファイルのファイル名
+const CONFIG_FILE_NAME: &str = ".uroborosqlfmtrc.json";
+
+/// 標準入力から読み込むことを表す入力ファイル名
+const STDIN: &str = "-";
+
+/// キャッシュファイルのファイル名
+const CACHE_FILE_NAME: &str = ".uroborosql-fmt-cache";
+
+/// コマンドライン引数
+struct Args {
+    /// 設定ファイルの JSON Schema を出力する
+    print_config_schema: bool,
+    /// ファイルを書き換えず、フォーマットが必要なファイルを列挙する
+    check: bool,
+    /// ファイルをフォーマット結果で上書きする
+    write: bool,
+    /// フォーマット済みのファイルを記録し、次回以降の実行で内容が変わっていないファイルを読み飛ばす
+    cache: bool,
+    /// 指定したファイルに適用される設定を出力する
+    show_config: Option<String>,
+    /// 使用する設定ファイルのパス
+    config: Option<String>,
+    /// 設定ファイルより優先させる設定 (`キー=値`)
+    settings: Vec<(String, String)>,
+    /// 標準入力から読み込む場合の、元のファイルのパス
+    /// 設定ファイルはこのファイルのディレクトリから探す
+    stdin_filepath: Option<String>,
+    /// フラグ以外の引数
+    paths: Vec<String>,
+}
+
+impl Args {
+    fn parse() -> Result<Args, String> {
+        Args::parse_from(std::env::args().skip(1))
+    }
+
+    /// プログラム名を除いたコマンドライン引数を解析する
+    fn parse_from(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+        let mut print_config_schema = false;
+        let mut check = false;
+        let mut write = false;
+        let mut cache = false;
+        let mut show_config = None;
+        let mut config = None;
+        let mut settings = vec![];
+        let mut stdin_filepath = None;
+        let mut paths = vec![];
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--print-config-schema" => print_config_schema = true,
+                "--check" => check = true,
+                "--write" => write = true,
+                "--cache" => cache = true,
+                "--show-config" => {
+                    let path = args.next().ok_or("--show-config requires a path")?;
+                    show_config = Some(path);
+                }
+                "--config" => {
+                    let path = args.next().ok_or("--config requires a path")?;
+                    config = Some(path);
+                }
+                "--set" => {
+                    let setting = args.next().ok_or("--set requires a key=value pair")?;
+                    let (key, value) = setting
+                        .split_once('=')
+                        .ok_or_else(|| format!("invalid setting: {setting}"))?;
+                    settings.push((key.to_string(), value.to_string()));
+                }
+                "--stdin-filepath" => {
+                    let path = args.next().ok_or("--stdin-filepath requires a path")?;
+                    stdin_filepath = Some(path);
+                }
+                flag if flag.starts_with("--") => return Err(format!("unknown option: {flag}")),
+                _ => paths.push(arg),
+            }
+        }
+
+        if check && write {
+            return Err("--check and --write cannot be used together".to_string());
+        }
+
+        if cache && !check && !write {
+            return Err("--cache requires --check or --write".to_string());
+        }
+
+        if cache && paths.iter().any(|path| path == STDIN) {
+            return Err("--cache cannot be used with standard input".to_string());
+        }
+
+        if paths.is_empty() && show_config.is_none() && !print_config_schema {
+            return Err("no input file".to_string());
+        }
+
+        if write && paths.iter().any(|path| path == STDIN) {
+            return Err("--write cannot be used with standard input".to_string());
+        }
+
+        if stdin_filepath.is_some() && !paths.iter().any(|path| path == STDIN) {
+            return Err("--stdin-filepath requires reading from standard input (-)".to_string());
+        }
+
+        Ok(Args {
+            print_config_schema,
+            check,
+            write,
+            cache,
+            show_config,
+            config,
+            settings,
+            stdin_filepath,
+            paths,
+        })
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("arguments error: {e}");
+            return ExitCode::from(EXIT_ERROR);
+        }
+    };
+
+    // 設定ファイルの JSON Schema を出力する
+    if args.print_config_schema {
+        println!("{:#}", json_schema());
+        return ExitCode::SUCCESS;
+    }
+
+    let settings_json = settings_json(&args.settings);
+    let resolver = ConfigResolver {
+        config: args.config.as_deref(),
+        settings_json: settings_json.as_deref(),
+        stdin_filepath: args.stdin_filepath.as_deref(),
+    };
+
+    if let Some(path) = &args.show_config {
+        return show_config(path, &resolver);
+    }
+
+    if args.check || args.write {
+        let mut cache = if args.cache {
+            cache_key().map(|key| Cache::load(CACHE_FILE_NAME, key))
+        } else {
             None
+        };
+
+        let exit_code = if args.check {
+            check(&args.paths, &resolver, &mut cache)
+        } else {
+            write(&args.paths, &resolver, &mut cache)
+        };
+
+        if let Some(Err(e)) = cache.map(|cache| cache.save()) {
+            eprintln!("{CACHE_FILE_NAME}: {e}");
+        }
+
+        return exit_code;
+    }
+
+    let input_file = &args.paths[0];
+    let output_file = args.paths.get(1);
+
+    let config_path = resolver.config_path(input_file);
+    if config_path.is_none() {
+        eprintln!("hint: Create the file '.uroborosqlfmtrc.json' if you want to customize the configuration");
+    }
+
+    let src = match read_input(input_file) {
+        Ok(src) => src,
+        Err(e) => {
+            eprintln!("{input_file}: {e}");
+            return ExitCode::from(EXIT_ERROR);
         }
     };
 
-    let result = match format_sql(src.as_ref(), None, config_path) {
-        Ok(res) => res,
+    let result = match format_sql_with_report(
+        src.as_ref(),
+        resolver.settings_json,
+        config_path.as_deref(),
+    ) {
+        Ok((res, warnings)) => {
+            print_warnings(input_file, &warnings);
+            res
+        }
         Err(e) => {
             eprintln!("{e}");
             src
@@ -31,9 +217,530 @@ fn main() {
 
     match output_file {
         Some(path) => {
-            let mut file = File::create(path).unwrap();
-            file.write_all(result.as_bytes()).unwrap();
+            if let Err(e) =
+                File::create(path).and_then(|mut file| file.write_all(result.as_bytes()))
+            {
+                eprintln!("{path}: {e}");
+                return ExitCode::from(EXIT_ERROR);
+            }
         }
+        // 標準入力から読み込んだ場合は、エディタなどから結果をそのまま使えるように改行を追加しない
+        None if input_file == STDIN => print!("{result}"),
         None => println!("{result}"),
     }
+
+    ExitCode::SUCCESS
+}
+
+/// 入力ファイルごとに適用する設定を解決する
+struct ConfigResolver<'a> {
+    /// `--config` で指定された設定ファイルのパス
+    /// 指定された場合は設定ファイルを探索しない
+    config: Option<&'a str>,
+    /// `--set` で指定された設定の JSON 文字列
+    settings_json: Option<&'a str>,
+    /// 標準入力から読み込む場合の、元のファイルのパス
+    stdin_filepath: Option<&'a str>,
+}
+
+impl ConfigResolver<'_> {
+    /// 入力ファイルに適用する設定ファイルのパスを返す。
+    /// 入力ファイルのディレクトリから親ディレクトリをたどり、最も近い設定ファイルを使用する。
+    /// 標準入力から読み込む場合は、`--stdin-filepath` で指定されたファイル (指定されていない場合はカレントディレクトリ) から探す。
+    fn config_path(&self, path: &str) -> Option<String> {
+        if let Some(config) = self.config {
+            return Some(config.to_string());
+        }
+
+        let dir = match (path, self.stdin_filepath) {
+            (STDIN, None) => Path::new("."),
+            (STDIN, Some(stdin_filepath)) => parent_dir(stdin_filepath),
+            _ => parent_dir(path),
+        };
+
+        find_config(dir).map(|path| path.to_string_lossy().into_owned())
+    }
+
+    /// 入力ファイルに適用する設定を読み込み、設定に対する警告とともに返す
+    fn load(&self, path: &str) -> Result<(Config, Vec<Warning>), String> {
+        Config::new_with_warnings(self.settings_json, self.config_path(path).as_deref())
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// `--set` で指定された設定を、設定ファイルより優先させる設定の JSON 文字列に変換する。
+/// 値は JSON として解釈できる場合はその値とし、そうでない場合は文字列とする。
+fn settings_json(settings: &[(String, String)]) -> Option<String> {
+    if settings.is_empty() {
+        return None;
+    }
+
+    let settings: serde_json::Map<_, _> = settings
+        .iter()
+        .map(|(key, value)| {
+            let value = serde_json::from_str(value)
+                .unwrap_or_else(|_| serde_json::Value::String(value.clone()));
+            (key.clone(), value)
+        })
+        .collect();
+
+    Some(serde_json::Value::Object(settings).to_string())
+}
+
+/// ファイルに適用される、デフォルト値、設定ファイル、`--set` で指定された設定から解決した設定を JSON で出力する。
+/// 適用された設定ファイルのパスは標準エラー出力に出力する。
+fn show_config(path: &str, resolver: &ConfigResolver) -> ExitCode {
+    let config_path = resolver.config_path(path);
+    eprintln!(
+        "config file: {}",
+        config_path.as_deref().unwrap_or("(none)")
+    );
+
+    match resolver.load(path) {
+        Ok((config, warnings)) => {
+            print_warnings(path, &warnings);
+            println!("{}", serde_json::to_string_pretty(&config).unwrap());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
+
+/// 各ファイルをメモリ上でフォーマットし、内容が変わるファイルを標準出力に列挙する。
+/// ファイルは書き換えない。
+fn check(paths: &[String], resolver: &ConfigResolver, cache: &mut Option<Cache>) -> ExitCode {
+    let mut needs_format = false;
+    let mut has_error = false;
+
+    for path in paths {
+        match format_file(path, resolver, cache) {
+            Ok((src, formatted)) if formatted != src => {
+                println!("{path}");
+                needs_format = true;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!("{path}: {e}");
+                has_error = true;
+            }
+        }
+    }
+
+    if has_error {
+        ExitCode::from(EXIT_ERROR)
+    } else if needs_format {
+        ExitCode::from(EXIT_NEEDS_FORMAT)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// 各ファイルをフォーマット結果で上書きする。
+/// 内容が変わらないファイルとフォーマットに失敗したファイルは書き換えない。
+fn write(paths: &[String], resolver: &ConfigResolver, cache: &mut Option<Cache>) -> ExitCode {
+    let mut has_error = false;
+
+    for path in paths {
+        let result = format_file(path, resolver, cache).and_then(|(src, formatted)| {
+            if formatted == src {
+                Ok(())
+            } else {
+                replace_file(Path::new(path), &formatted).map_err(|e| e.to_string())
+            }
+        });
+
+        if let Err(e) = result {
+            eprintln!("{path}: {e}");
+            has_error = true;
+        }
+    }
+
+    if has_error {
+        ExitCode::from(EXIT_ERROR)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// ファイルを読み込んでフォーマットし、(元の内容, フォーマット結果) を返す。
+/// 元のファイルに BOM が付いている場合は、フォーマット結果にも BOM を付ける。
+///
+/// 設定は1度だけ解決し、JSON に変換した設定をフォーマットとキャッシュの両方に使用する。
+///
+/// キャッシュにフォーマット済みとして記録されている場合は、フォーマットせずに元の内容を返す。
+/// そうでない場合は、フォーマット結果をキャッシュに記録する。
+/// 設定が変わるとフォーマット結果も変わりうるため、キャッシュには設定も合わせて記録する。
+fn format_file(
+    path: &str,
+    resolver: &ConfigResolver,
+    cache: &mut Option<Cache>,
+) -> Result<(String, String), String> {
+    let src = read_input(path).map_err(|e| e.to_string())?;
+
+    let (config, warnings) = resolver.load(path)?;
+    print_warnings(path, &warnings);
+    let config = serde_json::to_string(&config).map_err(|e| e.to_string())?;
+
+    if cache
+        .as_ref()
+        .is_some_and(|cache| cache.is_formatted(path, &config, &src))
+    {
+        return Ok((src.clone(), src));
+    }
+
+    let (bom, sql) = match src.strip_prefix(BOM) {
+        Some(sql) => (BOM.to_string(), sql),
+        None => (String::new(), src.as_str()),
+    };
+
+    // 解決済みの設定をすべて指定するため、設定ファイルは読み込まない
+    let (formatted, warnings) =
+        format_sql_with_report(sql, Some(&config), None).map_err(|e| e.to_string())?;
+    print_warnings(path, &warnings);
+    let formatted = bom + &formatted;
+
+    if let Some(cache) = cache {
+        cache.insert(path, &config, &formatted);
+    }
+
+    Ok((src, formatted))
+}
+
+/// フォーマット中に発生した警告を、ファイルのパスとともに標準エラー出力に出力する
+fn print_warnings(path: &str, warnings: &[Warning]) {
+    for warning in warnings {
+        eprintln!("{path}: warning: {warning}");
+    }
+}
+
+/// ファイルの内容を読み込む。
+/// パスが `-` の場合は標準入力から読み込む。
+fn read_input(path: &str) -> std::io::Result<String> {
+    if path == STDIN {
+        let mut src = String::new();
+        std::io::stdin().read_to_string(&mut src)?;
+        Ok(src)
+    } else {
+        read_to_string(path)
+    }
+}
+
+/// ファイルのパスから、そのファイルが存在するディレクトリを返す
+fn parent_dir(path: &str) -> &Path {
+    match Path::new(path).parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    }
+}
+
+/// dir から親ディレクトリをたどり、最も近い設定ファイルのパスを返す
+fn find_config(dir: &Path) -> Option<PathBuf> {
+    let dir = fs::canonicalize(dir).ok()?;
+
+    dir.ancestors()
+        .map(|dir| dir.join(CONFIG_FILE_NAME))
+        .find(|path| path.is_file())
+}
+
+/// キャッシュのキーを計算する。
+/// 実行ファイルが変わるとフォーマット結果も変わりうるため、実行ファイルの更新日時からキーを計算する。
+fn cache_key() -> Option<u64> {
+    let exe_modified = std::env::current_exe()
+        .and_then(fs::metadata)
+        .and_then(|metadata| metadata.modified())
+        .ok()?;
+
+    let key = format!("{}\n{:?}", env!("CARGO_PKG_VERSION"), exe_modified);
+
+    Some(cache::hash(key.as_bytes()))
+}
+
+/// ファイルの内容を置き換える。
+/// 同じディレクトリの一時ファイルに書き込んでから名前を変更することで、
+/// 書き込みの途中で中断されても元のファイルが壊れないようにする。
+/// 元のファイルのパーミッションは引き継ぐ。
+fn replace_file(path: &Path, content: &str) -> std::io::Result<()> {
+    let permissions = fs::metadata(path)?.permissions();
+
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let tmp_path = path.with_file_name(format!(".{file_name}.uroborosql-fmt.tmp"));
+
+    let result = (|| {
+        let mut tmp_file = File::create(&tmp_path)?;
+        tmp_file.write_all(content.as_bytes())?;
+        tmp_file.sync_all()?;
+        fs::set_permissions(&tmp_path, permissions)?;
+        fs::rename(&tmp_path, path)
+    })();
+
+    if result.is_err() {
+        // 一時ファイルが残らないようにする
+        let _ = fs::remove_file(&tmp_path);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process::ExitCode;
+
+    use super::{
+        check, find_config, format_file, settings_json, write, Args, Cache, ConfigResolver, BOM,
+        CONFIG_FILE_NAME, EXIT_ERROR, EXIT_NEEDS_FORMAT, STDIN,
+    };
+
+    /// テストごとに空の一時ディレクトリを作成する
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("uroborosql-fmt-cli-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// 引数を解析し、エラーの場合はそのメッセージを返す
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse_from(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_check_and_write() {
+        let args = parse(&["--check", "a.sql", "b.sql"]).unwrap();
+        assert!(args.check);
+        assert!(!args.write);
+        assert_eq!(args.paths, vec!["a.sql", "b.sql"]);
+
+        assert_eq!(
+            parse(&["--check", "--write", "a.sql"]).err().as_deref(),
+            Some("--check and --write cannot be used together")
+        );
+        assert_eq!(parse(&["--check"]).err().as_deref(), Some("no input file"));
+        assert_eq!(
+            parse(&["--fix", "a.sql"]).err().as_deref(),
+            Some("unknown option: --fix")
+        );
+    }
+
+    // フォーマットはグローバル変数の設定を書き換えるため、並列実行しないよう1つのテストで実行する
+    #[test]
+    fn test_format_files() {
+        check_exit_codes();
+        #[cfg(unix)]
+        write_replaces_file_in_place();
+        cache_is_keyed_by_resolved_config();
+    }
+
+    fn check_exit_codes() {
+        let dir = temp_dir("check");
+        let config = dir.join("config.json");
+        fs::write(&config, "{}").unwrap();
+        let resolver = ConfigResolver {
+            config: config.to_str(),
+            settings_json: None,
+            stdin_filepath: None,
+        };
+
+        let src = "SELECT a FROM t";
+        let formatted = uroborosql_fmt::format_sql(src, None, config.to_str()).unwrap();
+        let formatted_path = dir.join("formatted.sql");
+        let unformatted_path = dir.join("unformatted.sql");
+        fs::write(&formatted_path, formatted).unwrap();
+        fs::write(&unformatted_path, src).unwrap();
+
+        let path = |path: PathBuf| path.to_string_lossy().into_owned();
+
+        assert_eq!(
+            check(&[path(formatted_path.clone())], &resolver, &mut None),
+            ExitCode::SUCCESS
+        );
+        assert_eq!(
+            check(
+                &[path(formatted_path.clone()), path(unformatted_path.clone())],
+                &resolver,
+                &mut None
+            ),
+            ExitCode::from(EXIT_NEEDS_FORMAT)
+        );
+        // フォーマットが必要なファイルがあっても、読み込めないファイルがあればエラーとする
+        assert_eq!(
+            check(
+                &[path(unformatted_path), path(dir.join("missing.sql"))],
+                &resolver,
+                &mut None
+            ),
+            ExitCode::from(EXIT_ERROR)
+        );
+
+        // check はファイルを書き換えない
+        assert_eq!(
+            fs::read_to_string(dir.join("unformatted.sql")).unwrap(),
+            src
+        );
+    }
+
+    #[cfg(unix)]
+    fn write_replaces_file_in_place() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = temp_dir("write");
+        let config = dir.join("config.json");
+        fs::write(&config, "{}").unwrap();
+        let resolver = ConfigResolver {
+            config: config.to_str(),
+            settings_json: None,
+            stdin_filepath: None,
+        };
+
+        let src = "SELECT a FROM t";
+        let path = dir.join("a.sql");
+        fs::write(&path, format!("{BOM}{src}")).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+
+        let paths = [path.to_string_lossy().into_owned()];
+        assert_eq!(write(&paths, &resolver, &mut None), ExitCode::SUCCESS);
+
+        // BOM とパーミッションを引き継ぎ、一時ファイルを残さない
+        let formatted = uroborosql_fmt::format_sql(src, None, config.to_str()).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            format!("{BOM}{formatted}")
+        );
+        assert_eq!(
+            fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+            0o640
+        );
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+    }
+
+    #[test]
+    fn stdin_filepath_requires_stdin() {
+        let args = parse(&["--stdin-filepath", "dir/a.sql", "-"]).unwrap();
+        assert_eq!(args.stdin_filepath.as_deref(), Some("dir/a.sql"));
+
+        assert_eq!(
+            parse(&["--stdin-filepath", "dir/a.sql", "b.sql"])
+                .err()
+                .as_deref(),
+            Some("--stdin-filepath requires reading from standard input (-)")
+        );
+        assert_eq!(
+            parse(&["--write", "-"]).err().as_deref(),
+            Some("--write cannot be used with standard input")
+        );
+    }
+
+    fn cache_is_keyed_by_resolved_config() {
+        let dir = temp_dir("cache");
+        let config = dir.join("config.json");
+        fs::write(&config, "{}").unwrap();
+        let resolver = ConfigResolver {
+            config: config.to_str(),
+            settings_json: None,
+            stdin_filepath: None,
+        };
+
+        let src = "SELECT a FROM t";
+        let path = dir.join("a.sql");
+        fs::write(&path, src).unwrap();
+        let path = path.to_string_lossy().into_owned();
+
+        // 解決済みの設定で、現在の内容をフォーマット済みとして記録する
+        let (resolved, _) = resolver.load(&path).unwrap();
+        let resolved = serde_json::to_string(&resolved).unwrap();
+        let mut cache = Cache::load(dir.join("cache"), 0);
+        cache.insert(&path, &resolved, src);
+        let mut cache = Some(cache);
+
+        // キャッシュに記録されている場合は、フォーマットしない
+        let (_, formatted) = format_file(&path, &resolver, &mut cache).unwrap();
+        assert_eq!(formatted, src);
+
+        // 設定が変わった場合は、フォーマットして結果を記録する
+        let resolver = ConfigResolver {
+            settings_json: Some(r#"{"keyword_case": "upper"}"#),
+            ..resolver
+        };
+        let (_, formatted) = format_file(&path, &resolver, &mut cache).unwrap();
+        assert_ne!(formatted, src);
+        assert_eq!(
+            formatted,
+            uroborosql_fmt::format_sql(src, resolver.settings_json, config.to_str()).unwrap()
+        );
+    }
+
+    #[test]
+    fn settings_to_json() {
+        assert_eq!(settings_json(&[]), None);
+
+        let args = parse(&[
+            "--set",
+            "keyword_case=upper",
+            "--set",
+            "tab_size=2",
+            "--set",
+            "complement_alias=false",
+            "--set",
+            "trailing_comment_column=null",
+            "a.sql",
+        ])
+        .unwrap();
+
+        // JSON として解釈できる値はその値とし、そうでない値は文字列とする
+        let json: serde_json::Value =
+            serde_json::from_str(&settings_json(&args.settings).unwrap()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "keyword_case": "upper",
+                "tab_size": 2,
+                "complement_alias": false,
+                "trailing_comment_column": null,
+            })
+        );
+
+        assert_eq!(
+            parse(&["--set", "tab_size", "a.sql"]).err().as_deref(),
+            Some("invalid setting: tab_size")
+        );
+    }
+
+    #[test]
+    fn find_nearest_config_in_ancestors() {
+        let dir = temp_dir("find-config");
+        let nested = dir.join("a").join("b");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(dir.join(CONFIG_FILE_NAME), "{}").unwrap();
+
+        let root_config = fs::canonicalize(dir.join(CONFIG_FILE_NAME)).unwrap();
+        assert_eq!(find_config(&nested), Some(root_config));
+
+        // より近いディレクトリの設定ファイルを優先する
+        fs::write(dir.join("a").join(CONFIG_FILE_NAME), "{}").unwrap();
+        let nearest_config = fs::canonicalize(dir.join("a").join(CONFIG_FILE_NAME)).unwrap();
+        assert_eq!(find_config(&nested), Some(nearest_config.clone()));
+
+        // 入力ファイルのディレクトリから探し、標準入力の場合は --stdin-filepath のディレクトリから探す
+        let file = nested.join("x.sql").to_string_lossy().into_owned();
+        let resolver = ConfigResolver {
+            config: None,
+            settings_json: None,
+            stdin_filepath: Some(&file),
+        };
+        let expected = Some(nearest_config.to_string_lossy().into_owned());
+        assert_eq!(resolver.config_path(&file), expected);
+        assert_eq!(resolver.config_path(STDIN), expected);
+
+        // --config が指定された場合は探さない
+        let resolver = ConfigResolver {
+            config: Some("other.json"),
+            ..resolver
+        };
+        assert_eq!(resolver.config_path(&file).as_deref(), Some("other.json"));
+    }
 }
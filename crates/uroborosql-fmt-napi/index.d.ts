@@ -3,5 +3,11 @@
 
 /* auto-generated by NAPI-RS */
 
+/** フォーマット結果と、フォーマット中に発生した警告 */
+export interface FormatReport {
+  result: string
+  warnings: Array<string>
+}
 export function runfmt(input: string, configPath?: string | undefined | null): string
 export function runfmtWithSettings(input: string, settingsJson: string, configPath?: string | undefined | null): string
+export function runfmtWithReport(input: string, settingsJson?: string | undefined | null, configPath?: string | undefined | null): FormatReport
@@ -1,7 +1,7 @@
 #![deny(clippy::all)]
 
 use napi::{Error, Result, Status};
-use uroborosql_fmt::format_sql;
+use uroborosql_fmt::{format_sql, format_sql_with_report};
 
 #[macro_use]
 extern crate napi_derive;
@@ -25,3 +25,25 @@ pub fn runfmt_with_settings(
   format_sql(&input, Some(&settings_json), config_path)
     .map_err(|e| Error::new(Status::GenericFailure, format!("{e}")))
 }
+
+/// フォーマット結果と、フォーマット中に発生した警告
+#[napi(object)]
+pub struct FormatReport {
+  pub result: String,
+  pub warnings: Vec<String>,
+}
+
+#[napi]
+pub fn runfmt_with_report(
+  input: String,
+  settings_json: Option<String>,
+  config_path: Option<&str>,
+) -> Result<FormatReport> {
+  let (result, warnings) = format_sql_with_report(&input, settings_json.as_deref(), config_path)
+    .map_err(|e| Error::new(Status::GenericFailure, format!("{e}")))?;
+
+  Ok(FormatReport {
+    result,
+    warnings: warnings.iter().map(|warning| warning.to_string()).collect(),
+  })
+}
@@ -6,8 +6,9 @@ use std::{
 
 static RESULT: Lazy<Mutex<CString>> = Lazy::new(|| Mutex::new(CString::new("").unwrap()));
 static ERROR_MSG: Lazy<Mutex<CString>> = Lazy::new(|| Mutex::new(CString::new("").unwrap()));
+static WARNINGS: Lazy<Mutex<CString>> = Lazy::new(|| Mutex::new(CString::new("").unwrap()));
 
-use uroborosql_fmt::format_sql;
+use uroborosql_fmt::format_sql_with_report;
 
 /// Returns the address of the result string.
 ///
@@ -29,6 +30,17 @@ pub unsafe extern "C" fn get_error_msg_address() -> *const c_char {
     ERROR_MSG.lock().unwrap().as_c_str().as_ptr()
 }
 
+/// Returns the address of the warnings string.
+/// Each warning is separated by a newline.
+///
+/// # Safety
+///
+/// This is unsafe because it returns a raw pointer.
+#[no_mangle]
+pub unsafe extern "C" fn get_warnings_address() -> *const c_char {
+    WARNINGS.lock().unwrap().as_c_str().as_ptr()
+}
+
 /// Formats SQL code given as char pointer `src` by WASM (JavaScript).
 ///
 /// # Safety
@@ -41,14 +53,24 @@ pub unsafe extern "C" fn format_sql_for_wasm(src: *const c_char, config_json_str
     // Clear previous format result
     *RESULT.lock().unwrap() = CString::new("").unwrap();
     *ERROR_MSG.lock().unwrap() = CString::new("").unwrap();
+    *WARNINGS.lock().unwrap() = CString::new("").unwrap();
 
     let src = CStr::from_ptr(src).to_str().unwrap().to_owned();
 
     let settings_json = CStr::from_ptr(config_json_str).to_str().unwrap();
-    let result = format_sql(&src, Some(settings_json), None);
+    let result = format_sql_with_report(&src, Some(settings_json), None);
 
     match result {
-        Ok(result) => *RESULT.lock().unwrap() = CString::new(result).unwrap(),
+        Ok((result, warnings)) => {
+            *RESULT.lock().unwrap() = CString::new(result).unwrap();
+
+            let warnings = warnings
+                .iter()
+                .map(|warning| warning.to_string())
+                .collect::<Vec<_>>()
+                .join("\n");
+            *WARNINGS.lock().unwrap() = CString::new(warnings).unwrap();
+        }
         Err(err) => *ERROR_MSG.lock().unwrap() = CString::new(err.to_string()).unwrap(),
     }
 }
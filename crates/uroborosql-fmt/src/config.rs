@@ -3,9 +3,13 @@ use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::fs::File;
 use std::io::BufReader;
+use std::path::{Path, PathBuf};
 use std::sync::RwLock;
 
-use crate::error::UroboroSQLFmtError;
+use crate::{
+    error::UroboroSQLFmtError,
+    warning::{Span, Warning, WarningKind},
+};
 
 /// 設定を保持するグローバル変数
 pub(crate) static CONFIG: Lazy<RwLock<Config>> = Lazy::new(|| RwLock::new(Config::default()));
@@ -75,7 +79,93 @@ fn default_indent_tab() -> bool {
     true
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+/// preserve_blank_linesのデフォルト値(0)
+fn default_preserve_blank_lines() -> usize {
+    0
+}
+
+/// best_effortのデフォルト値(false)
+fn default_best_effort() -> bool {
+    false
+}
+
+/// function_caseのデフォルト値(None: keyword_caseに従う)
+fn default_function_case() -> Option<Case> {
+    None
+}
+
+/// type_caseのデフォルト値(None: keyword_caseに従う)
+fn default_type_case() -> Option<Case> {
+    None
+}
+
+/// trailing_comment_columnのデフォルト値(None: 式の長さに合わせて揃える)
+fn default_trailing_comment_column() -> Option<usize> {
+    None
+}
+
+/// semicolon_positionのデフォルト値(own_line)
+fn default_semicolon_position() -> SemicolonPosition {
+    SemicolonPosition::default()
+}
+
+/// complement_column_aliasのデフォルト値(None: 補完しない)
+fn default_complement_column_alias() -> Option<ColumnAliasNaming> {
+    None
+}
+
+/// remove_outer_keywordのデフォルト値(false)
+fn default_remove_outer_keyword() -> bool {
+    false
+}
+
+/// remove_inner_keywordのデフォルト値(false)
+fn default_remove_inner_keyword() -> bool {
+    false
+}
+
+/// normalize_bind_paramのデフォルト値(false)
+fn default_normalize_bind_param() -> bool {
+    false
+}
+
+/// in_list_max_elementsのデフォルト値(None: 制限しない)
+fn default_in_list_max_elements() -> Option<usize> {
+    None
+}
+
+/// in_list_max_widthのデフォルト値(None: 制限しない)
+fn default_in_list_max_width() -> Option<usize> {
+    None
+}
+
+/// max_line_lengthのデフォルト値(None: 折り返さない)
+fn default_max_line_length() -> Option<usize> {
+    None
+}
+
+/// 識別子以外の式に補完するカラムエイリアスの命名方法
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ColumnAliasNaming {
+    /// PostgreSQL が自動で付与するカラム名と同じ名前 (例: `count(*)` → `count`)
+    Postgresql,
+    /// 関数名と引数のカラム名を `_` で連結した名前 (例: `max(t.amount)` → `max_amount`)
+    SnakeCase,
+}
+
+/// 文末のセミコロンの位置
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum SemicolonPosition {
+    /// 文の後の行にセミコロンのみを置く
+    #[default]
+    OwnLine,
+    /// 文の最後の行の末尾にセミコロンを置く
+    EndOfLine,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub(crate) enum Case {
     Upper,
@@ -101,7 +191,7 @@ impl Case {
 }
 
 /// 設定を保持する構造体
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Config {
     /// デバッグモード
     #[serde(default = "default_debug")]
@@ -154,6 +244,45 @@ pub struct Config {
     /// 空白文字ではなくタブ文字でインデントする
     #[serde(default = "default_indent_tab")]
     pub(crate) indent_tab: bool,
+    /// 文と文、句と句の間の空行を指定した行数まで保持する (0 の場合は保持しない)
+    #[serde(default = "default_preserve_blank_lines")]
+    pub(crate) preserve_blank_lines: usize,
+    /// サポートしていない文をそのまま出力し、それ以外の文のフォーマットを続ける
+    #[serde(default = "default_best_effort")]
+    pub(crate) best_effort: bool,
+    /// 関数名を大文字・小文字にする (指定しない場合は keyword_case に従う)
+    #[serde(default = "default_function_case")]
+    pub(crate) function_case: Option<Case>,
+    /// 型名を大文字・小文字にする (指定しない場合は keyword_case に従う)
+    #[serde(default = "default_type_case")]
+    pub(crate) type_case: Option<Case>,
+    /// 行末コメントを指定した列に揃える (指定しない場合は最長の式に合わせて揃える)
+    #[serde(default = "default_trailing_comment_column")]
+    pub(crate) trailing_comment_column: Option<usize>,
+    /// 識別子以外の式にカラムエイリアスを補完する際の命名方法 (指定しない場合は補完しない)
+    #[serde(default = "default_complement_column_alias")]
+    pub(crate) complement_column_alias: Option<ColumnAliasNaming>,
+    /// OUTERキーワードを除去する (complement_outer_keyword より優先する)
+    #[serde(default = "default_remove_outer_keyword")]
+    pub(crate) remove_outer_keyword: bool,
+    /// INNERキーワードを除去する
+    #[serde(default = "default_remove_inner_keyword")]
+    pub(crate) remove_inner_keyword: bool,
+    /// バインドパラメータの空白をトリムし、値との間の空白を取り除く
+    #[serde(default = "default_normalize_bind_param")]
+    pub(crate) normalize_bind_param: bool,
+    /// IN のリストを1行で描画する要素数の上限 (指定しない場合は制限しない)
+    #[serde(default = "default_in_list_max_elements")]
+    pub(crate) in_list_max_elements: Option<usize>,
+    /// IN のリストを1行で描画する幅の上限 (指定しない場合は制限しない)
+    #[serde(default = "default_in_list_max_width")]
+    pub(crate) in_list_max_width: Option<usize>,
+    /// IN のリストを含む行の長さの上限 (指定しない場合は折り返さない)
+    #[serde(default = "default_max_line_length")]
+    pub(crate) max_line_length: Option<usize>,
+    /// 文末のセミコロンの位置
+    #[serde(default = "default_semicolon_position")]
+    pub(crate) semicolon_position: SemicolonPosition,
 }
 
 impl Config {
@@ -165,18 +294,21 @@ impl Config {
         settings_json: Option<&str>,
         config_path: Option<&str>,
     ) -> Result<Config, UroboroSQLFmtError> {
+        Config::new_with_warnings(settings_json, config_path).map(|(config, _)| config)
+    }
+
+    /// `Config::new` と同様に Config 構造体を生成し、設定に含まれる未知のキーに対する警告を返す。
+    ///
+    /// Returns `Config` in the same way as `Config::new`, with warnings for unknown keys in the options.
+    pub fn new_with_warnings(
+        settings_json: Option<&str>,
+        config_path: Option<&str>,
+    ) -> Result<(Config, Vec<Warning>), UroboroSQLFmtError> {
         let mut config = serde_json::Map::new();
 
         // 設定ファイルから読み込む
         if let Some(path) = config_path {
-            let file = File::open(path).map_err(|_| {
-                UroboroSQLFmtError::FileNotFound("Setting file not found".to_string())
-            })?;
-
-            let reader = BufReader::new(file);
-
-            let file_config: serde_json::Map<_, _> = serde_json::from_reader(reader)
-                .map_err(|e| UroboroSQLFmtError::IllegalSettingFile(e.to_string()))?;
+            let file_config = read_config_file(Path::new(path), &mut vec![])?;
 
             config.extend(file_config);
         };
@@ -191,11 +323,178 @@ impl Config {
                     ))
                 })?;
 
+            // extends は設定ファイルのディレクトリを基準に解決するため、設定ファイルでのみ指定できる
+            if settings.contains_key("extends") {
+                return Err(UroboroSQLFmtError::Runtime(
+                    "\"extends\" can only be specified in a configuration file".to_string(),
+                ));
+            }
+
             config.extend(settings);
         }
 
-        serde_json::from_value(serde_json::Value::Object(config))
-            .map_err(|e| UroboroSQLFmtError::Runtime(e.to_string()))
+        let mut warnings = migrate_deprecated_keys(&mut config);
+        warnings.extend(unknown_key_warnings(&config));
+
+        let config = serde_json::from_value(serde_json::Value::Object(config))
+            .map_err(|e| UroboroSQLFmtError::Runtime(e.to_string()))?;
+
+        Ok((config, warnings))
+    }
+}
+
+/// 非推奨の設定キーと、その代わりに使用する設定キー
+const DEPRECATED_KEYS: [(&str, &str); 1] =
+    [("remove_redundant_parentheses", "remove_redundant_nest")];
+
+/// 設定に含まれる非推奨のキーを、代わりに使用するキーに置き換え、非推奨のキーに対する警告を返す。
+/// 代わりに使用するキーが既に指定されている場合は、そちらの値を優先する。
+fn migrate_deprecated_keys(
+    config: &mut serde_json::Map<String, serde_json::Value>,
+) -> Vec<Warning> {
+    let mut warnings = vec![];
+
+    for (deprecated_key, new_key) in DEPRECATED_KEYS {
+        if let Some(value) = config.remove(deprecated_key) {
+            warnings.push(Warning::new(
+                WarningKind::DeprecatedConfigKey,
+                format!(
+                    "configuration key `{deprecated_key}` is deprecated. Use `{new_key}` instead."
+                ),
+                Span::default(),
+            ));
+
+            if !config.contains_key(new_key) {
+                config.insert(new_key.to_string(), value);
+            }
+        }
+    }
+
+    warnings
+}
+
+/// 設定に含まれる未知のキーに対する警告を返す。
+/// 未知のキーに近い名前のオプションがあれば、警告のメッセージで提案する。
+fn unknown_key_warnings(config: &serde_json::Map<String, serde_json::Value>) -> Vec<Warning> {
+    let known_keys = match serde_json::to_value(Config::default()) {
+        Ok(serde_json::Value::Object(map)) => map.keys().cloned().collect(),
+        _ => vec![],
+    };
+
+    config
+        .keys()
+        .filter(|key| !known_keys.contains(key))
+        .map(|key| {
+            let suggestion = known_keys
+                .iter()
+                .map(|known| (levenshtein_distance(key, known), known))
+                .filter(|(distance, _)| *distance <= 3)
+                .min_by_key(|(distance, _)| *distance);
+
+            let message = match suggestion {
+                Some((_, known)) => {
+                    format!("unknown configuration key `{key}`. Did you mean `{known}`?")
+                }
+                None => format!("unknown configuration key `{key}`"),
+            };
+
+            Warning::new(WarningKind::UnknownConfigKey, message, Span::default())
+        })
+        .collect()
+}
+
+/// 2つの文字列のレーベンシュタイン距離を返す
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    // 直前の行の距離
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            current[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(current[j] + 1);
+        }
+
+        prev = current;
+    }
+
+    prev[b.len()]
+}
+
+/// 設定ファイルを読み込む。
+/// `extends` が指定されている場合は、継承元の設定を読み込み、その上に設定ファイルの内容を上書きする。
+/// visited には継承をたどる中で読み込んだ設定ファイルのパスを保持し、循環している場合はエラーを返す。
+fn read_config_file(
+    path: &Path,
+    visited: &mut Vec<PathBuf>,
+) -> Result<serde_json::Map<String, serde_json::Value>, UroboroSQLFmtError> {
+    let file = File::open(path)
+        .map_err(|_| UroboroSQLFmtError::FileNotFound("Setting file not found".to_string()))?;
+
+    let canonical_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if visited.contains(&canonical_path) {
+        return Err(UroboroSQLFmtError::IllegalSettingFile(format!(
+            "Circular extends is detected: {}",
+            path.display()
+        )));
+    }
+    visited.push(canonical_path);
+
+    let reader = BufReader::new(file);
+
+    let mut file_config: serde_json::Map<_, _> = serde_json::from_reader(reader)
+        .map_err(|e| UroboroSQLFmtError::IllegalSettingFile(e.to_string()))?;
+
+    let Some(extends) = file_config.remove("extends") else {
+        return Ok(file_config);
+    };
+
+    let extends = extends.as_str().ok_or_else(|| {
+        UroboroSQLFmtError::IllegalSettingFile("\"extends\" must be a string".to_string())
+    })?;
+
+    let mut config = if let Some(preset) = preset_config(extends) {
+        preset
+    } else {
+        // 継承元のパスは設定ファイルのディレクトリからの相対パスとして解決する
+        let base_path = path
+            .parent()
+            .map_or_else(|| PathBuf::from(extends), |dir| dir.join(extends));
+
+        read_config_file(&base_path, visited)?
+    };
+
+    config.extend(file_config);
+
+    Ok(config)
+}
+
+/// `extends` に指定できるプリセットの設定を返す。
+/// プリセット名でない場合は None を返す。
+fn preset_config(name: &str) -> Option<serde_json::Map<String, serde_json::Value>> {
+    match name {
+        // フューチャー株式会社のSQLコーディング規約に従った設定
+        // デフォルトの設定が変わってもプリセットの内容は変わらないよう、値を明示する
+        "future-coding-standard" => match serde_json::json!({
+            "tab_size": 4,
+            "indent_tab": true,
+            "keyword_case": "lower",
+            "identifier_case": "lower",
+            "max_char_per_line": 50,
+            "complement_alias": true,
+            "complement_outer_keyword": true,
+            "complement_column_as_keyword": true,
+            "remove_table_as_keyword": true,
+            "remove_redundant_nest": true,
+            "convert_double_colon_cast": true,
+            "unify_not_equal": true,
+        }) {
+            serde_json::Value::Object(map) => Some(map),
+            _ => None,
+        },
+        _ => None,
     }
 }
 
@@ -217,10 +516,138 @@ impl Default for Config {
             convert_double_colon_cast: default_convert_double_colon_cast(),
             unify_not_equal: default_unify_not_equal(),
             indent_tab: default_indent_tab(),
+            preserve_blank_lines: default_preserve_blank_lines(),
+            best_effort: default_best_effort(),
+            function_case: default_function_case(),
+            type_case: default_type_case(),
+            trailing_comment_column: default_trailing_comment_column(),
+            complement_column_alias: default_complement_column_alias(),
+            remove_outer_keyword: default_remove_outer_keyword(),
+            remove_inner_keyword: default_remove_inner_keyword(),
+            normalize_bind_param: default_normalize_bind_param(),
+            in_list_max_elements: default_in_list_max_elements(),
+            in_list_max_width: default_in_list_max_width(),
+            max_line_length: default_max_line_length(),
+            semicolon_position: default_semicolon_position(),
         }
     }
 }
 
+/// 設定ファイル (`.uroborosqlfmtrc.json`) の JSON Schema を返す。
+///
+/// Returns the JSON Schema of the configuration file (`.uroborosqlfmtrc.json`).
+pub fn json_schema() -> serde_json::Value {
+    let case = |description: &str| {
+        serde_json::json!({
+            "type": "string",
+            "enum": ["upper", "lower", "preserve"],
+            "description": description,
+        })
+    };
+    let optional_case = |description: &str| {
+        serde_json::json!({
+            "type": ["string", "null"],
+            "enum": ["upper", "lower", "preserve", null],
+            "description": description,
+        })
+    };
+    let boolean = |description: &str| {
+        serde_json::json!({
+            "type": "boolean",
+            "description": description,
+        })
+    };
+
+    let mut properties = serde_json::json!({
+        "extends": {
+            "type": "string",
+            "description": "Inherit settings from another configuration file or a preset (\"future-coding-standard\").",
+        },
+        "debug": boolean("Run in debug mode."),
+        "tab_size": {
+            "type": "integer",
+            "minimum": 1,
+            "description": "Tab size used for formatting.",
+        },
+        "complement_alias": boolean("Complement aliases. Currently, column names are auto-completed with the same name."),
+        "trim_bind_param": boolean("Trim the contents of the bind parameters."),
+        "keyword_case": case("Unify the case of keywords."),
+        "identifier_case": case("Unify the case of identifiers."),
+        "function_case": optional_case("Unify the case of function names. Follows keyword_case if not specified."),
+        "type_case": optional_case("Unify the case of type names in casts. Follows keyword_case if not specified."),
+        "max_char_per_line": {
+            "type": "integer",
+            "description": "If the total number of characters in a function call or an IN expression exceeds max_char_per_line, the arguments or list elements are formatted with new lines.",
+        },
+        "complement_outer_keyword": boolean("Complement the optional OUTER."),
+        "complement_column_as_keyword": boolean("Complement AS in column aliases."),
+        "remove_table_as_keyword": boolean("Remove AS in table aliases."),
+        "remove_redundant_nest": boolean("Remove redundant parentheses."),
+        "complement_sql_id": boolean("Complement SQL ID."),
+        "convert_double_colon_cast": boolean("Convert casts by X::type to the form CAST(X AS type)."),
+        "unify_not_equal": boolean("Convert comparison operator <> to !="),
+        "indent_tab": boolean("Switch the indentation style between tabs and spaces."),
+        "preserve_blank_lines": {
+            "type": "integer",
+            "minimum": 0,
+            "description": "Keep up to the specified number of blank lines between statements and between clauses.",
+        },
+        "best_effort": boolean("Leave unsupported statements unformatted instead of failing the whole file."),
+        "complement_column_alias": {
+            "type": ["string", "null"],
+            "enum": ["postgresql", "snake_case", null],
+            "description": "Complement aliases of expressions other than identifiers with the specified naming strategy.",
+        },
+        "remove_outer_keyword": boolean("Remove the optional OUTER. Takes precedence over complement_outer_keyword."),
+        "remove_inner_keyword": boolean("Remove the optional INNER."),
+        "normalize_bind_param": boolean("Normalize bind parameters adjacent to their values to the form /*param*/value if the parameter name is valid."),
+        "in_list_max_elements": {
+            "type": ["integer", "null"],
+            "minimum": 0,
+            "description": "If an IN list has more elements than in_list_max_elements, the elements are formatted one per line.",
+        },
+        "in_list_max_width": {
+            "type": ["integer", "null"],
+            "minimum": 0,
+            "description": "If an IN list is wider than in_list_max_width, the elements are formatted one per line.",
+        },
+        "max_line_length": {
+            "type": ["integer", "null"],
+            "minimum": 0,
+            "description": "If a line containing an IN list is longer than max_line_length, the elements are formatted one per line.",
+        },
+        "semicolon_position": {
+            "type": "string",
+            "enum": ["own_line", "end_of_line"],
+            "description": "Place the terminating semicolon on its own line or at the end of the last line.",
+        },
+        "trailing_comment_column": {
+            "type": ["integer", "null"],
+            "minimum": 0,
+            "description": "Align trailing comments to the specified column. Aligned to the longest expression if not specified.",
+        },
+    });
+
+    // デフォルト値は Config::default() から設定する
+    if let (Some(properties), Ok(serde_json::Value::Object(defaults))) = (
+        properties.as_object_mut(),
+        serde_json::to_value(Config::default()),
+    ) {
+        for (key, default) in defaults {
+            if let Some(serde_json::Value::Object(property)) = properties.get_mut(&key) {
+                property.insert("default".to_string(), default);
+            }
+        }
+    }
+
+    serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "uroborosql-fmt configuration",
+        "type": "object",
+        "properties": properties,
+    })
+}
+
 /// 引数に与えた Config 構造体をグローバル変数 CONFIG に読み込む
 pub(crate) fn load_settings(config: Config) {
     *CONFIG.write().unwrap() = config
@@ -244,7 +671,178 @@ pub(crate) fn load_never_complement_settings() {
         convert_double_colon_cast: false,
         unify_not_equal: false,
         indent_tab: true,
+        preserve_blank_lines: default_preserve_blank_lines(),
+        best_effort: default_best_effort(),
+        function_case: default_function_case(),
+        type_case: default_type_case(),
+        trailing_comment_column: default_trailing_comment_column(),
+        complement_column_alias: None,
+        remove_outer_keyword: false,
+        remove_inner_keyword: false,
+        normalize_bind_param: false,
+        in_list_max_elements: default_in_list_max_elements(),
+        in_list_max_width: default_in_list_max_width(),
+        max_line_length: default_max_line_length(),
+        semicolon_position: default_semicolon_position(),
     };
 
     *CONFIG.write().unwrap() = config;
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use serde_json::json;
+
+    use super::{
+        json_schema, levenshtein_distance, read_config_file, unknown_key_warnings, Config,
+    };
+    use crate::{error::UroboroSQLFmtError, warning::WarningKind};
+
+    /// テストごとに空の一時ディレクトリを作成する
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "uroborosql-fmt-config-{name}-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// 設定ファイルを作成する
+    fn write_config(path: &Path, content: serde_json::Value) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content.to_string()).unwrap();
+    }
+
+    #[test]
+    fn circular_extends_is_error() {
+        let dir = temp_dir("circular");
+        write_config(&dir.join("a.json"), json!({ "extends": "b.json" }));
+        write_config(&dir.join("b.json"), json!({ "extends": "a.json" }));
+
+        let result = read_config_file(&dir.join("a.json"), &mut vec![]);
+
+        assert!(matches!(
+            result,
+            Err(UroboroSQLFmtError::IllegalSettingFile(msg)) if msg.contains("Circular extends")
+        ));
+    }
+
+    #[test]
+    fn extends_is_resolved_relative_to_each_file() {
+        let dir = temp_dir("nested");
+        write_config(
+            &dir.join("project/.uroborosqlfmtrc.json"),
+            json!({ "extends": "../shared/base.json", "keyword_case": "upper" }),
+        );
+        write_config(
+            &dir.join("shared/base.json"),
+            json!({ "extends": "presets/common.json", "tab_size": 2 }),
+        );
+        write_config(
+            &dir.join("shared/presets/common.json"),
+            json!({ "tab_size": 8, "indent_tab": false }),
+        );
+
+        let config =
+            read_config_file(&dir.join("project/.uroborosqlfmtrc.json"), &mut vec![]).unwrap();
+
+        assert_eq!(config.get("keyword_case"), Some(&json!("upper")));
+        assert_eq!(config.get("tab_size"), Some(&json!(2)));
+        assert_eq!(config.get("indent_tab"), Some(&json!(false)));
+        assert_eq!(config.get("extends"), None);
+    }
+
+    #[test]
+    fn extends_preset() {
+        let dir = temp_dir("preset");
+        write_config(
+            &dir.join(".uroborosqlfmtrc.json"),
+            json!({ "extends": "future-coding-standard", "tab_size": 2 }),
+        );
+
+        let config = read_config_file(&dir.join(".uroborosqlfmtrc.json"), &mut vec![]).unwrap();
+
+        assert_eq!(config.get("tab_size"), Some(&json!(2)));
+        assert_eq!(config.get("keyword_case"), Some(&json!("lower")));
+        assert_eq!(config.get("complement_alias"), Some(&json!(true)));
+
+        // プリセットのキーはすべて既知のオプションである
+        assert!(unknown_key_warnings(&config).is_empty());
+    }
+
+    #[test]
+    fn extends_in_settings_json_is_error() {
+        let result = Config::new_with_warnings(Some(r#"{"extends": "../base.json"}"#), None);
+
+        assert!(matches!(
+            result,
+            Err(UroboroSQLFmtError::Runtime(msg)) if msg.contains("configuration file")
+        ));
+    }
+
+    #[test]
+    fn json_schema_covers_all_options() {
+        let schema = json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+        let config = serde_json::to_value(Config::default()).unwrap();
+
+        // extends は設定ファイルでのみ指定するキーであるため、Config には含まれない
+        let mut schema_keys = properties
+            .keys()
+            .filter(|key| *key != "extends")
+            .collect::<Vec<_>>();
+        let mut config_keys = config.as_object().unwrap().keys().collect::<Vec<_>>();
+        schema_keys.sort();
+        config_keys.sort();
+
+        assert_eq!(schema_keys, config_keys);
+    }
+
+    #[test]
+    fn levenshtein() {
+        assert_eq!(levenshtein_distance("", ""), 0);
+        assert_eq!(levenshtein_distance("tab_size", "tab_size"), 0);
+        assert_eq!(levenshtein_distance("tab_siez", "tab_size"), 2);
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+        assert_eq!(levenshtein_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn unknown_key_suggests_closest_option() {
+        let (config, warnings) =
+            Config::new_with_warnings(Some(r#"{"tab_siez": 2, "foo": true}"#), None).unwrap();
+
+        // 未知のキーは無視される
+        assert_eq!(config.tab_size, Config::default().tab_size);
+
+        let messages = warnings
+            .iter()
+            .map(|warning| (warning.kind, warning.message.as_str()))
+            .collect::<Vec<_>>();
+        assert!(messages.contains(&(
+            WarningKind::UnknownConfigKey,
+            "unknown configuration key `tab_siez`. Did you mean `tab_size`?"
+        )));
+        // 近い名前のオプションがない場合は提案しない
+        assert!(messages.contains(&(
+            WarningKind::UnknownConfigKey,
+            "unknown configuration key `foo`"
+        )));
+    }
+
+    #[test]
+    fn deprecated_key_is_migrated() {
+        let (config, warnings) =
+            Config::new_with_warnings(Some(r#"{"remove_redundant_parentheses": false}"#), None)
+                .unwrap();
+
+        assert!(!config.remove_redundant_nest);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, WarningKind::DeprecatedConfigKey);
+    }
+}
@@ -29,7 +29,12 @@ pub(crate) use with::*;
 use itertools::{repeat_n, Itertools};
 use tree_sitter::{Node, Point, Range};
 
-use crate::{config::CONFIG, error::UroboroSQLFmtError, re::RE, util::add_indent};
+use crate::{
+    config::CONFIG,
+    error::UroboroSQLFmtError,
+    re::RE,
+    util::{add_indent, is_bind_param_name},
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Position {
@@ -111,6 +116,22 @@ impl Comment {
         self.text.starts_with("/*")
     }
 
+    /// コメントが単一行のヒント句 (`/*+ ... */`) であればtrueを返す
+    pub(crate) fn is_single_line_hint(&self) -> bool {
+        self.text.starts_with("/*+") && self.loc.is_single_line()
+    }
+
+    /// コメントが loc の式に付与するバインドパラメータであればtrueを返す
+    /// 式に隣接したブロックコメントをバインドパラメータとみなす。
+    pub(crate) fn is_bind_param_of(&self, loc: &Location) -> bool {
+        self.is_block_comment() && self.loc.is_next_to(loc)
+    }
+
+    /// コメントの内容が、前後の空白を除いてバインドパラメータ名として正しい形 (`/* param */` など) であればtrueを返す
+    pub(crate) fn has_bind_param_name(&self) -> bool {
+        self.is_block_comment() && is_bind_param_name(&self.text)
+    }
+
     pub(crate) fn is_two_way_sql_comment(&self) -> bool {
         RE.branching_keyword_re.find(self.text.as_str()).is_some()
     }
@@ -3,7 +3,7 @@ use itertools::Itertools;
 use crate::{
     cst::{add_indent, AlignInfo, AlignedExpr, Comment, Location},
     error::UroboroSQLFmtError,
-    util::{add_single_space, add_space_by_range, tab_size, to_tab_num},
+    util::{add_space_by_range, add_trailing_comment, tab_size, to_tab_num},
 };
 
 #[derive(Debug, Clone)]
@@ -91,8 +91,8 @@ impl SepLinesContent {
                     result.push_str(&comment.render(depth - 1)?);
                 } else if is_first {
                     is_first = false;
-                    add_single_space(&mut result);
-                    result.push_str(&comment.render(0)?);
+                    // セパレータの行末コメント
+                    add_trailing_comment(&mut result, 0, &comment.render(0)?);
                 } else {
                     result.push_str(&comment.render(new_depth_with_sep)?);
                 }
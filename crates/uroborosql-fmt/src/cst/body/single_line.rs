@@ -1,6 +1,7 @@
 use crate::{
     cst::{AlignedExpr, Comment, Expr, Location},
     error::UroboroSQLFmtError,
+    util::tab_size,
 };
 
 #[derive(Debug, Clone)]
@@ -51,10 +52,19 @@ impl SingleLine {
 
     /// 先頭にインデントを挿入せずに render する。
     pub(crate) fn render(&self, depth: usize) -> Result<String, UroboroSQLFmtError> {
+        self.render_from_col(depth, depth * tab_size())
+    }
+
+    /// 先頭にインデントを挿入せずに、1行目を start_col 列目から描画する場合の render。
+    pub(crate) fn render_from_col(
+        &self,
+        depth: usize,
+        start_col: usize,
+    ) -> Result<String, UroboroSQLFmtError> {
         let mut result = String::new();
 
         // 式は一つのみであるため、縦ぞろえはしない
-        result.push_str(&self.expr.render(depth)?);
+        result.push_str(&self.expr.render_from_col(depth, start_col)?);
 
         result.push('\n');
         if !self.comments.is_empty() {
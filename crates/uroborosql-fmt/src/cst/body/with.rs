@@ -1,7 +1,7 @@
 use crate::{
     cst::{add_indent, ColumnList, Comment, Location, SubExpr},
     error::UroboroSQLFmtError,
-    util::add_single_space,
+    util::{add_single_space, add_trailing_comment, last_line_end_col},
 };
 
 /// WITH句における名前付きサブクエリ}
@@ -90,7 +90,12 @@ impl Cte {
         }
     }
 
-    pub(crate) fn render(&self, depth: usize) -> Result<String, UroboroSQLFmtError> {
+    /// 1行目を start_col 列目から描画する場合の render
+    pub(crate) fn render(
+        &self,
+        depth: usize,
+        start_col: usize,
+    ) -> Result<String, UroboroSQLFmtError> {
         let mut result = String::new();
 
         result.push_str(&self.name);
@@ -104,7 +109,9 @@ impl Cte {
 
         // テーブル名の直後のコメントがある場合
         if let Some(comment) = &self.name_trailing_comment {
-            result.push_str(comment);
+            // 直前に空白を追加済みであるため取り除く
+            result.pop();
+            add_trailing_comment(&mut result, start_col, comment);
             result.push('\n');
             add_indent(&mut result, depth);
         }
@@ -121,8 +128,7 @@ impl Cte {
         result.push_str(&self.sub_expr.render(depth)?);
 
         if let Some(comment) = &self.trailing_comment {
-            add_single_space(&mut result);
-            result.push_str(comment);
+            add_trailing_comment(&mut result, start_col, comment);
         }
 
         Ok(result)
@@ -205,7 +211,7 @@ impl WithBody {
             }
             add_single_space(&mut result);
 
-            let formatted = cte.render(depth)?;
+            let formatted = cte.render(depth, last_line_end_col(&result, 0))?;
             result.push_str(&formatted);
             result.push('\n');
 
@@ -2,7 +2,7 @@ use tree_sitter::Node;
 
 use crate::{
     error::UroboroSQLFmtError,
-    util::{add_single_space, convert_keyword_case},
+    util::{add_single_space, convert_keyword_case, last_line_end_col},
 };
 
 use super::{add_indent, Body, Comment, Location, SqlID};
@@ -13,10 +13,14 @@ pub(crate) struct Clause {
     keyword: String, // e.g., SELECT, FROM
     body: Option<Body>,
     loc: Location,
+    /// キーワードと同じ行に固定するヒント句
+    hint: Option<Comment>,
     /// DML(, DDL)に付与できるsql_id
     sql_id: Option<SqlID>,
     /// キーワードの下に現れるコメント
     comments: Vec<Comment>,
+    /// 句の前に保持する空行の数
+    blank_lines_before: usize,
 }
 
 impl Clause {
@@ -29,8 +33,10 @@ impl Clause {
             keyword,
             body: None,
             loc,
+            hint: None,
             sql_id: None,
             comments: vec![],
+            blank_lines_before: 0,
         }
     }
 
@@ -103,6 +109,16 @@ impl Clause {
         Ok(())
     }
 
+    /// 句の前に保持する空行の数を指定する
+    pub(crate) fn set_blank_lines_before(&mut self, blank_lines: usize) {
+        self.blank_lines_before = blank_lines;
+    }
+
+    /// ヒント句をセットする
+    pub(crate) fn set_hint(&mut self, hint: Comment) {
+        self.hint = Some(hint);
+    }
+
     /// SQL_IDをセットする
     pub(crate) fn set_sql_id(&mut self, sql_id: SqlID) {
         self.sql_id = Some(sql_id);
@@ -130,11 +146,16 @@ impl Clause {
     pub(crate) fn render(&self, depth: usize) -> Result<String, UroboroSQLFmtError> {
         // kw
         // body...
-        let mut result = String::new();
+        let mut result = "\n".repeat(self.blank_lines_before);
 
         add_indent(&mut result, depth);
         result.push_str(&self.keyword);
 
+        if let Some(hint) = &self.hint {
+            result.push(' ');
+            result.push_str(&hint.text);
+        }
+
         if let Some(sql_id) = &self.sql_id {
             result.push(' ');
             result.push_str(&sql_id.sql_id);
@@ -150,7 +171,9 @@ impl Clause {
             // 句と本体を同じ行に render する
             Some(Body::SingleLine(single_line)) => {
                 add_single_space(&mut result);
-                result.push_str(&single_line.render(depth)?);
+                // 行末コメントを揃える列を計算するため、キーワードの後の列を与える
+                let start_col = last_line_end_col(&result, 0);
+                result.push_str(&single_line.render_from_col(depth, start_col)?);
             }
             Some(body) => {
                 let formatted_body = body.render(depth + 1)?;
@@ -1,7 +1,10 @@
 use crate::{
     cst::{add_indent, Comment, Location},
     error::UroboroSQLFmtError,
-    util::{add_single_space, add_space_by_range, tab_size, to_tab_num},
+    util::{
+        add_comment_at_column, add_space_by_range, add_trailing_comment, tab_size, to_tab_num,
+        trailing_comment_column,
+    },
 };
 
 use super::Expr;
@@ -113,6 +116,11 @@ impl AlignedExpr {
         self.loc.clone()
     }
 
+    /// 左辺の式を返す
+    pub(crate) fn lhs(&self) -> &Expr {
+        &self.lhs
+    }
+
     /// opのタブ文字換算の長さを返す (opが存在しない場合はNone)
     ///
     /// 例えばtab_sizeが4、opがbetweenの場合
@@ -248,10 +256,20 @@ impl AlignedExpr {
 
     /// 演算子・コメントの縦ぞろえをせずにrenderする
     pub(crate) fn render(&self, depth: usize) -> Result<String, UroboroSQLFmtError> {
+        self.render_from_col(depth, depth * tab_size())
+    }
+
+    /// 演算子・コメントの縦ぞろえをせずに、1行目を start_col 列目から描画する場合の render。
+    /// 句のキーワードの後など、インデントの直後以外の位置に描画する場合に使用する。
+    pub(crate) fn render_from_col(
+        &self,
+        depth: usize,
+        start_col: usize,
+    ) -> Result<String, UroboroSQLFmtError> {
         // 自身のみからAlignInfo作成
         let align_info = &AlignInfo::from(vec![self]);
 
-        self.render_align(depth, align_info)
+        self.render_align_from_col(depth, start_col, align_info)
     }
 
     /// 演算子までの長さを与え、演算子の前にtab文字を挿入した文字列を返す
@@ -260,9 +278,64 @@ impl AlignedExpr {
         depth: usize,
         // 縦揃え対象AligendExpr(自分を含む)の情報
         align_info: &AlignInfo,
+    ) -> Result<String, UroboroSQLFmtError> {
+        self.render_align_from_col(depth, depth * tab_size(), align_info)
+    }
+
+    /// 右辺が IN のリストであり、単一行で描画した行の長さが max_line_length を超える場合は、
+    /// 複数行で描画するように変更した右辺を返す。変更しない場合は None を返す。
+    /// start_col には1行目が描画される列を与える。
+    fn wrap_rhs_at_max_line_length(
+        &self,
+        depth: usize,
+        start_col: usize,
+        align_info: &AlignInfo,
+    ) -> Option<Expr> {
+        // 左辺に行末コメントがある場合や左辺がCASE文の場合は、演算子の前で改行するため対象外
+        if self.lhs_trailing_comment.is_some() || matches!(self.lhs, Expr::Cond(_)) {
+            return None;
+        }
+
+        let (Some(Expr::ColumnList(list)), Some(max_tab_num_to_op), Some(max_op_tab_num)) = (
+            &self.rhs,
+            align_info.max_tab_num_to_op,
+            align_info.max_op_tab_num,
+        ) else {
+            return None;
+        };
+
+        // 左辺が複数行の場合、演算子は左辺の最後の行 (インデントの直後から始まる) に続く
+        let line_start = if self.lhs.is_multi_line() {
+            depth * tab_size()
+        } else {
+            start_col
+        };
+        // 右辺は縦揃えした演算子の後の列から描画される
+        let rhs_col = line_start + (max_tab_num_to_op + max_op_tab_num) * tab_size();
+
+        if !list.exceeds_max_line_length(rhs_col) {
+            return None;
+        }
+
+        let mut list = list.clone();
+        list.set_force_multi_line(true);
+        Some(Expr::ColumnList(list))
+    }
+
+    /// render_align と同様に描画する。
+    /// start_col には1行目が描画される列を与え、行末コメントを揃える列の計算に使用する。
+    fn render_align_from_col(
+        &self,
+        depth: usize,
+        start_col: usize,
+        align_info: &AlignInfo,
     ) -> Result<String, UroboroSQLFmtError> {
         let mut result = String::new();
 
+        // 右辺の IN のリストを単一行で描画した行の長さが max_line_length を超える場合は、複数行で描画する
+        let wrapped_rhs = self.wrap_rhs_at_max_line_length(depth, start_col, align_info);
+        let rhs = wrapped_rhs.as_ref().or(self.rhs.as_ref());
+
         // 演算子の最長の長さをタブ換算したもの
         // AlignedExprの式が1つも演算子を持っていない場合はNone
         let max_op_tab_num = align_info.max_op_tab_num;
@@ -301,8 +374,7 @@ impl AlignedExpr {
                         ));
                     }
 
-                    add_single_space(&mut result);
-                    result.push_str(comment_str);
+                    add_trailing_comment(&mut result, start_col, comment_str);
                     result.push('\n');
 
                     // インデントを挿入
@@ -336,13 +408,13 @@ impl AlignedExpr {
 
                     // 右辺が存在してCASE文ではない場合はタブを挿入
                     // CASE文の場合はopの直後で改行するため、opの後にはタブを挿入しない
-                    if self.rhs.is_some() && !matches!(&self.rhs, Some(Expr::Cond(_))) {
+                    if rhs.is_some() && !matches!(rhs, Some(Expr::Cond(_))) {
                         add_space_by_range(&mut result, op.len(), max_op_tab_num * tab_size());
                     }
                 }
 
                 //右辺をrender
-                if let Some(rhs) = &self.rhs {
+                if let Some(rhs) = rhs {
                     let formatted = if matches!(rhs, Expr::Cond(_)) {
                         // 右辺がCASE文の場合は改行してタブを挿入
                         result.push('\n');
@@ -355,7 +427,7 @@ impl AlignedExpr {
                     result.push_str(&formatted);
                 }
             // 演算子を持たないが右辺が存在する場合、右辺を他の右辺に縦揃えする
-            } else if self.rhs.is_some() {
+            } else if rhs.is_some() {
                 if let Some(comment_str) = &self.lhs_trailing_comment {
                     if depth < 1 {
                         // 左辺に行末コメントがある場合、右辺の直前にタブ文字が挿入されるため、
@@ -366,8 +438,7 @@ impl AlignedExpr {
                         ));
                     }
 
-                    add_single_space(&mut result);
-                    result.push_str(comment_str);
+                    add_trailing_comment(&mut result, start_col, comment_str);
                     result.push('\n');
 
                     // インデントを挿入
@@ -397,13 +468,13 @@ impl AlignedExpr {
 
                 // 右辺が存在してCASE文ではない場合はタブを挿入
                 // CASE文の場合はopの直後で改行するため、opの後にはタブを挿入しない
-                if self.rhs.is_some() && !matches!(&self.rhs, Some(Expr::Cond(_))) {
+                if rhs.is_some() && !matches!(rhs, Some(Expr::Cond(_))) {
                     let tab_num = max_op_tab_num; // self.op != Noneならop_tab_num != None
                     add_indent(&mut result, tab_num);
                 }
 
                 //右辺をrender
-                if let Some(rhs) = &self.rhs {
+                if let Some(rhs) = rhs {
                     let formatted = if matches!(rhs, Expr::Cond(_)) {
                         // 右辺がCASE文の場合は改行してタブを挿入
                         result.push('\n');
@@ -418,8 +489,13 @@ impl AlignedExpr {
             }
         }
 
-        // 行末コメントが存在する場合
-        if let Some(trailing_comment) = &self.trailing_comment {
+        if let (Some(trailing_comment), Some(column)) =
+            (&self.trailing_comment, trailing_comment_column())
+        {
+            // 行末コメントが存在し、揃える列が設定で指定されている場合
+            add_comment_at_column(&mut result, start_col, trailing_comment, column);
+        } else if let Some(trailing_comment) = &self.trailing_comment {
+            // 行末コメントが存在し、揃える列が設定で指定されていない場合
             // 行末コメントが存在する場合はmax_tab_num_to_commentはSome(_)
             let max_tab_num_to_comment = max_tab_num_to_comment.unwrap();
 
@@ -428,7 +504,7 @@ impl AlignedExpr {
                 let max_op_tab_num = max_op_tab_num.unwrap();
                 let max_tab_num_to_op = max_tab_num_to_op.unwrap();
 
-                let (start_col, end_col) = if let Some(rhs) = &self.rhs {
+                let (start_col, end_col) = if let Some(rhs) = rhs {
                     // 右辺がある場合は、コメントまでの最長の長さ - 右辺の長さ
 
                     // trailing_commentがある場合、max_tab_num_to_commentは必ずSome(_)
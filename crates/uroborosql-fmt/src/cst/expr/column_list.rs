@@ -3,7 +3,10 @@ use itertools::Itertools;
 use crate::{
     cst::{add_indent, AlignInfo, AlignedExpr, Comment, Location},
     error::UroboroSQLFmtError,
-    util::{add_space_by_range, count_width, tab_size, trim_bind_param},
+    util::{
+        add_space_by_range, count_width, max_line_length, normalize_bind_param, tab_size,
+        trim_bind_param,
+    },
 };
 
 /// 列のリストを表す。
@@ -18,6 +21,9 @@ pub(crate) struct ColumnList {
     head_comment: Option<String>,
     /// 開き括弧と最初の式との間のコメント
     start_comments: Vec<Comment>,
+    /// 単一行で描画した行の長さが max_line_length を超える場合に、複数行で描画するかを指定するフラグ。
+    /// 現状では IN のリストのみ true になる。
+    wrap_at_max_line_length: bool,
 }
 
 impl ColumnList {
@@ -32,6 +38,7 @@ impl ColumnList {
             force_multi_line: false,
             head_comment: None,
             start_comments,
+            wrap_at_max_line_length: false,
         }
     }
 
@@ -63,10 +70,20 @@ impl ColumnList {
         }
     }
 
+    /// 列の数を返す
+    pub(crate) fn col_count(&self) -> usize {
+        self.cols.len()
+    }
+
+    /// 列が2つ以上あれば true を返す
+    pub(crate) fn has_multiple_cols(&self) -> bool {
+        self.cols.len() > 1
+    }
+
     pub(crate) fn set_head_comment(&mut self, comment: Comment) {
         let Comment { text, mut loc } = comment;
 
-        let text = trim_bind_param(text);
+        let text = normalize_bind_param(trim_bind_param(text));
 
         self.head_comment = Some(text);
         loc.append(self.loc());
@@ -79,6 +96,19 @@ impl ColumnList {
         self.force_multi_line = b
     }
 
+    /// 単一行で描画した行の長さが max_line_length を超える場合に、複数行で描画するかを指定する。
+    pub(crate) fn set_wrap_at_max_line_length(&mut self, b: bool) {
+        self.wrap_at_max_line_length = b
+    }
+
+    /// 開き括弧を start_col 列目から単一行で描画した際に、行の長さが max_line_length を超える場合 true を返す。
+    /// 既に複数行で描画する場合や、max_line_length による折り返しの対象でない場合は false を返す。
+    pub(crate) fn exceeds_max_line_length(&self, start_col: usize) -> bool {
+        self.wrap_at_max_line_length
+            && !self.is_multi_line()
+            && max_line_length().is_some_and(|max_len| self.last_line_len(start_col) > max_len)
+    }
+
     /// 複数行で描画するかどうかを bool 型の値で取得する。
     /// 複数行で描画する場合は true を返す。
     /// 自身の is_multi_line のオプションの値だけでなく、開き括弧と最初の式との間にコメントを持つどうか、各列が単一行かどうか、各行が末尾コメントを持つかどうかも考慮する。
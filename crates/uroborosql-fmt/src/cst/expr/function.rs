@@ -40,6 +40,11 @@ impl FunctionCallArgs {
         self.force_multi_line
     }
 
+    /// 引数の式のスライスを返す
+    pub(crate) fn exprs(&self) -> &[AlignedExpr] {
+        &self.exprs
+    }
+
     pub(crate) fn add_expr(&mut self, cols: AlignedExpr) {
         self.loc.append(cols.loc());
         self.exprs.push(cols);
@@ -237,6 +242,16 @@ impl FunctionCall {
         }
     }
 
+    /// 関数名を返す
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 引数を返す
+    pub(crate) fn args(&self) -> &FunctionCallArgs {
+        &self.args
+    }
+
     pub(crate) fn set_filter_clause(&mut self, clause: Clause) {
         self.loc.append(clause.loc());
         self.filter_where_clause = Some(clause)
@@ -4,15 +4,17 @@ use crate::{
     cst::{Comment, Location},
     error::UroboroSQLFmtError,
     util::{
-        convert_identifier_case, convert_keyword_case, count_width, is_quoted, trim_bind_param,
+        convert_identifier_case, convert_keyword_case, convert_type_case, count_width, is_quoted,
+        normalize_bind_param, normalize_type_name, trim_bind_param,
     },
 };
 
-/// PrimaryExprがKeywordかExprか示すEnum
+/// PrimaryExprがKeyword、Expr、型名のいずれであるかを示すEnum
 #[derive(Clone, Debug)]
 pub(crate) enum PrimaryExprKind {
     Expr,
     Keyword,
+    Type,
 }
 
 /// 識別子、リテラルを表す。
@@ -23,6 +25,8 @@ pub(crate) struct PrimaryExpr {
     loc: Location,
     /// バインドパラメータ
     head_comment: Option<String>,
+    /// キーワードとして生成されたかどうか
+    is_keyword: bool,
 }
 
 impl PrimaryExpr {
@@ -31,6 +35,7 @@ impl PrimaryExpr {
             element: element.into(),
             loc,
             head_comment: None,
+            is_keyword: false,
         }
     }
 
@@ -41,15 +46,18 @@ impl PrimaryExpr {
         let element = node.utf8_text(src.as_bytes()).unwrap();
 
         // PrimaryExprKindによって適用するルールを変更する
-        let converted_element = if matches!(kind, PrimaryExprKind::Keyword) {
+        let converted_element = match kind {
             // キーワードの大文字小文字設定を適用した文字列
-            convert_keyword_case(element)
-        } else {
+            PrimaryExprKind::Keyword => convert_keyword_case(element),
+            // 空白を正規化し、型名の大文字小文字設定を適用した文字列
+            PrimaryExprKind::Type => convert_type_case(&normalize_type_name(element)),
             // 文字列リテラルであればそのまま、DBオブジェクトであれば大文字小文字設定を適用した文字列
-            convert_identifier_case(element)
+            PrimaryExprKind::Expr => convert_identifier_case(element),
         };
 
-        PrimaryExpr::new(converted_element, Location::new(node.range()))
+        let mut primary = PrimaryExpr::new(converted_element, Location::new(node.range()));
+        primary.is_keyword = matches!(kind, PrimaryExprKind::Keyword);
+        primary
     }
 
     pub(crate) fn loc(&self) -> Location {
@@ -81,11 +89,21 @@ impl PrimaryExpr {
         !is_quoted && !is_num
     }
 
+    /// 式がカラム名として使用できる識別子であるかどうかを返す。
+    /// is_identifier() に加えて、数値リテラル (`1.5` や `.5` を含む)、キーワード (`NULL` や `CURRENT_DATE` など) を除外する。
+    pub(crate) fn is_column_name(&self) -> bool {
+        let is_num = self
+            .element
+            .starts_with(|c: char| c.is_ascii_digit() || c == '.');
+
+        self.is_identifier() && !is_num && !self.is_keyword
+    }
+
     /// バインドパラメータをセットする
     pub(crate) fn set_head_comment(&mut self, comment: Comment) {
         let Comment { text, mut loc } = comment;
 
-        let text = trim_bind_param(text);
+        let text = normalize_bind_param(trim_bind_param(text));
 
         self.head_comment = Some(text);
         loc.append(self.loc.clone());
@@ -22,6 +22,11 @@ impl TypeCast {
         }
     }
 
+    /// キャストされる式を返す
+    pub(crate) fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
     pub(crate) fn loc(&self) -> Location {
         self.loc.clone()
     }
@@ -1,4 +1,7 @@
-use crate::error::UroboroSQLFmtError;
+use crate::{
+    config::{SemicolonPosition, CONFIG},
+    error::UroboroSQLFmtError,
+};
 
 use super::{Clause, Comment, Location};
 
@@ -11,6 +14,10 @@ pub(crate) struct Statement {
     comments: Vec<Comment>,
     /// 末尾にセミコロンがついているか
     has_semi: bool,
+    /// 文が行コメントで終わっているか (最後の行の末尾に行コメントが出力される)
+    ends_with_line_comment: bool,
+    /// 文の前に保持する空行の数
+    blank_lines_before: usize,
 }
 
 impl Statement {
@@ -20,9 +27,16 @@ impl Statement {
             loc: None,
             comments: vec![] as Vec<Comment>,
             has_semi: false,
+            ends_with_line_comment: false,
+            blank_lines_before: 0,
         }
     }
 
+    /// Clauseのスライスへの可変参照を取得する
+    pub(crate) fn clauses_mut(&mut self) -> &mut [Clause] {
+        &mut self.clauses
+    }
+
     /// ClauseのVecへの参照を取得する
     pub(crate) fn get_clauses(self) -> Vec<Clause> {
         self.clauses
@@ -61,16 +75,26 @@ impl Statement {
         self.comments.push(comment);
     }
 
+    /// 文の前に保持する空行の数を指定する
+    pub(crate) fn set_blank_lines_before(&mut self, blank_lines: usize) {
+        self.blank_lines_before = blank_lines;
+    }
+
     /// 末尾にセミコロンがつくかどうかを指定する
     pub(crate) fn set_semi(&mut self, has_semi: bool) {
         self.has_semi = has_semi;
     }
 
+    /// 文が行コメントで終わっているかどうかを指定する
+    pub(crate) fn set_ends_with_line_comment(&mut self, ends_with_line_comment: bool) {
+        self.ends_with_line_comment = ends_with_line_comment;
+    }
+
     pub(crate) fn render(&self, depth: usize) -> Result<String, UroboroSQLFmtError> {
         // clause1
         // ...
         // clausen
-        let mut result = String::new();
+        let mut result = "\n".repeat(self.blank_lines_before);
 
         for comment in &self.comments {
             result.push_str(&comment.render(depth)?);
@@ -83,6 +107,15 @@ impl Statement {
         }
 
         if self.has_semi {
+            // 文が行コメントで終わる場合は、セミコロンがコメントに含まれてしまうため行末には置かない
+            let is_end_of_line = CONFIG.read().unwrap().semicolon_position
+                == SemicolonPosition::EndOfLine
+                && !self.ends_with_line_comment;
+
+            if is_end_of_line {
+                result.truncate(result.trim_end_matches('\n').len());
+            }
+
             result.push_str(";\n");
         }
 
@@ -2,21 +2,31 @@ pub mod config;
 mod cst;
 pub mod error;
 mod re;
+mod segment;
 mod two_way_sql;
 mod util;
 mod validate;
 mod visitor;
+pub mod warning;
 
 use config::*;
 use error::UroboroSQLFmtError;
 use visitor::Visitor;
 
+use segment::{
+    is_do_block, is_format_disabled, misplaced_format_markers, place_at_offset, split_segments,
+    statement_ranges, Segment,
+};
 use tree_sitter::{Language, Node, Tree};
 use two_way_sql::{format_two_way_sql, is_two_way_sql};
 use validate::validate_format_result;
+use warning::{Span, Warning, WarningKind};
 
 /// 設定ファイルより優先させるオプションを JSON 文字列で与えて、SQLのフォーマットを行う。
 ///
+/// フォーマット中に発生した警告は返さないため、そのまま出力した文などを知る必要がある場合は
+/// `format_sql_with_report` を使用する。
+///
 /// Format sql with json string that describes higher priority options than the configuration file.
 pub fn format_sql(
     src: &str,
@@ -25,14 +35,186 @@ pub fn format_sql(
 ) -> Result<String, UroboroSQLFmtError> {
     let config = Config::new(settings_json, config_path)?;
 
-    format_sql_with_config(src, config)
+    format_sql_with_config(src, config, &mut vec![])
+}
+
+/// `format_sql` と同様にSQLのフォーマットを行い、フォーマット結果とフォーマット中に発生した警告を返す。
+/// 警告には、フォーマットせずに出力した文、移動したコメント、補完したキーワードとそのソース上の範囲、
+/// および設定に含まれる未知のキー、非推奨のキーが含まれる。
+/// ただし、2way-sqlモードでフォーマットした場合は警告を報告しない。
+///
+/// Format sql in the same way as `format_sql`, and return the result with the warnings that occurred while formatting.
+pub fn format_sql_with_report(
+    src: &str,
+    settings_json: Option<&str>,
+    config_path: Option<&str>,
+) -> Result<(String, Vec<Warning>), UroboroSQLFmtError> {
+    let (config, mut warnings) = Config::new_with_warnings(settings_json, config_path)?;
+
+    let result = format_sql_with_config(src, config, &mut warnings)?;
+
+    Ok((result, warnings))
 }
 
 /// 設定をConfig構造体で渡して、SQLをフォーマットする。
+///
+/// DO ブロックなどフォーマットできない文はそのまま出力し、その前後の文をそれぞれフォーマットする。
+/// best_effort が有効な場合、サポートしていない文もそのまま出力する。
+/// そのまま出力した文は、いずれも warnings に警告として追加する。
 pub(crate) fn format_sql_with_config(
     src: &str,
-    config: Config,
+    mut config: Config,
+    warnings: &mut Vec<Warning>,
 ) -> Result<String, UroboroSQLFmtError> {
+    // ファイル全体でフォーマットが無効にされている場合はそのまま返す
+    if is_format_disabled(src) {
+        return Ok(src.to_owned());
+    }
+
+    // 文の途中に書かれた `-- uroborosql-fmt:off` / `on` を含む文は、文全体をそのまま出力する
+    for (start, end) in misplaced_format_markers(src) {
+        warnings.push(Warning::new(
+            WarningKind::MisplacedFormatMarker,
+            format!(
+                "`{}` is inside a statement, so the whole statement was left unformatted",
+                src[start..end].trim()
+            ),
+            Span::from_byte_range(src, start, end),
+        ));
+    }
+
+    let mut result = String::new();
+    // 直前のセグメントの内容の終了位置
+    let mut prev_end = None;
+
+    for segment in split_segments(src) {
+        // セグメントの境界にある空行は、セグメント中の空行と同様に保持する
+        let (start, end) = segment.content_range();
+        if let Some(prev_end) = prev_end {
+            push_blank_lines(
+                &mut result,
+                &src[prev_end..start],
+                config.preserve_blank_lines,
+            );
+        }
+        prev_end = Some(end);
+
+        match segment {
+            Segment::Sql { text, offset } => {
+                let segment = place_at_offset(src, offset, text);
+                let formatted = match format_segment(&segment, config.clone()) {
+                    Ok((formatted, segment_warnings)) => {
+                        warnings.extend(segment_warnings);
+                        formatted
+                    }
+                    Err(e) if config.best_effort && is_unsupported_error(&e) => {
+                        format_statements_best_effort(src, text, offset, &config, warnings)?
+                    }
+                    Err(e) => return Err(e),
+                };
+                result.push_str(&formatted);
+                // SQL_IDの補完はファイル中で1度だけ行う
+                config.complement_sql_id = false;
+            }
+            Segment::Verbatim { text, offset } => {
+                result.push_str(text);
+                result.push('\n');
+
+                if is_do_block(text) {
+                    let span = Span::from_byte_range(src, offset, offset + text.len());
+                    warnings.push(Warning::new(
+                        WarningKind::UnsupportedStatement,
+                        "DO block was left unformatted",
+                        span,
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// 文ごとにフォーマットを行い、フォーマットできない文はそのまま出力する。
+/// sql はソース全体の offset バイト目から始まる断片である。
+fn format_statements_best_effort(
+    src: &str,
+    sql: &str,
+    offset: usize,
+    config: &Config,
+    warnings: &mut Vec<Warning>,
+) -> Result<String, UroboroSQLFmtError> {
+    let mut config = config.clone();
+    let mut result = String::new();
+    // 直前の文の終了位置
+    let mut prev_end = 0;
+
+    for (start, end) in statement_ranges(sql) {
+        // 文の上のコメントも含めてフォーマットする
+        let stmt = &sql[prev_end..end];
+
+        // 直前の文との間の空行を保持する
+        if prev_end > 0 {
+            let gap = &stmt[..stmt.len() - stmt.trim_start().len()];
+            push_blank_lines(&mut result, gap, config.preserve_blank_lines);
+        }
+
+        let placed_stmt = place_at_offset(src, offset + prev_end, stmt);
+        prev_end = end;
+
+        match format_segment(&placed_stmt, config.clone()) {
+            Ok((formatted, stmt_warnings)) => {
+                result.push_str(&formatted);
+                config.complement_sql_id = false;
+                warnings.extend(stmt_warnings);
+            }
+            Err(e) if is_unsupported_error(&e) => {
+                result.push_str(stmt.trim());
+                result.push('\n');
+
+                let span = Span::from_byte_range(src, offset + start, offset + end);
+                warnings.push(Warning::new(
+                    WarningKind::UnsupportedStatement,
+                    format!("statement was left unformatted: {e}"),
+                    span,
+                ));
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    // 最後の文の後のコメント
+    let rest = &sql[prev_end..];
+    if !rest.trim().is_empty() {
+        let gap = &rest[..rest.len() - rest.trim_start().len()];
+        push_blank_lines(&mut result, gap, config.preserve_blank_lines);
+
+        result.push_str(rest.trim());
+        result.push('\n');
+    }
+
+    Ok(result)
+}
+
+/// 文やセグメントの間の空白 gap に含まれる空行を、max_blank_lines 行を上限として result に追加する
+fn push_blank_lines(result: &mut String, gap: &str, max_blank_lines: usize) {
+    let blank_lines = gap.matches('\n').count().saturating_sub(1);
+    result.push_str(&"\n".repeat(blank_lines.min(max_blank_lines)));
+}
+
+/// サポートしていない構文が原因のエラーであれば true を返す。
+/// フォーマット結果の検証エラーはフォーマッタの不具合であるため含めない。
+fn is_unsupported_error(e: &UroboroSQLFmtError) -> bool {
+    matches!(
+        e,
+        UroboroSQLFmtError::Unimplemented(_) | UroboroSQLFmtError::UnexpectedSyntax(_)
+    )
+}
+
+/// 分割されたソースの断片をフォーマットし、フォーマット結果と警告を返す。
+/// 断片は `place_at_offset` でソース全体と同じ位置に配置されているため、
+/// エラーと警告の位置はソース全体における位置となる。
+fn format_segment(src: &str, config: Config) -> Result<(String, Vec<Warning>), UroboroSQLFmtError> {
     // tree-sitter-sqlの言語を取得
     let language = tree_sitter_sql::language();
 
@@ -56,14 +238,15 @@ pub(crate) fn format_sql_with_config(
             eprintln!("\n{} 2way-sql mode {}\n", "=".repeat(20), "=".repeat(20));
         }
 
-        format_two_way_sql(src, language)
+        // 2way-sqlモードでは、分岐ごとに生成したSQLをフォーマットするため警告は報告しない
+        format_two_way_sql(src, language).map(|result| (result, vec![]))
     } else {
         // ノーマルモード
         if CONFIG.read().unwrap().debug {
             eprintln!("\n{} normal mode {}\n", "=".repeat(20), "=".repeat(20));
         }
 
-        format_tree(tree, src)
+        format_tree_with_warnings(tree, src)
     }
 }
 
@@ -79,6 +262,14 @@ pub(crate) fn format(src: &str, language: Language) -> Result<String, UroboroSQL
 
 /// 渡されたTreeをもとにフォーマットする
 pub(crate) fn format_tree(tree: Tree, src: &str) -> Result<String, UroboroSQLFmtError> {
+    format_tree_with_warnings(tree, src).map(|(result, _)| result)
+}
+
+/// 渡されたTreeをもとにフォーマットし、フォーマット結果と警告を返す
+pub(crate) fn format_tree_with_warnings(
+    tree: Tree,
+    src: &str,
+) -> Result<(String, Vec<Warning>), UroboroSQLFmtError> {
     // Treeのルートノードを取得
     let root_node = tree.root_node();
 
@@ -102,7 +293,7 @@ pub(crate) fn format_tree(tree: Tree, src: &str) -> Result<String, UroboroSQLFmt
         .map(|stmt| stmt.render(0).expect("render: error"))
         .collect();
 
-    Ok(result)
+    Ok((result, visitor.take_warnings()))
 }
 
 fn has_syntax_error(tree: &Tree) -> bool {
@@ -133,3 +324,334 @@ fn print_cst(node: Node, depth: usize) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::UroboroSQLFmtError,
+        format_sql, format_sql_with_report,
+        warning::{Span, Warning, WarningKind},
+    };
+
+    // 並列実行するとグローバル変数の問題が発生するため、1つのテストで実行する
+    #[test]
+    fn test_format_sql() -> Result<(), UroboroSQLFmtError> {
+        best_effort_leaves_unsupported_statement()?;
+        report_complemented_keywords()?;
+        report_positions_in_later_segment()?;
+        report_unknown_config_keys()?;
+        complement_column_alias_by_naming()?;
+        break_in_list_by_element_count()?;
+        break_in_list_by_line_length()?;
+        normalize_bind_param_spacing()?;
+        report_deprecated_config_keys()?;
+        datetime_function_follows_keyword_case()?;
+        semicolon_at_end_of_line()?;
+        reject_alias_after_using()?;
+        align_trailing_comments_to_column()?;
+        report_format_marker_inside_statement()
+    }
+
+    fn best_effort_leaves_unsupported_statement() -> Result<(), UroboroSQLFmtError> {
+        let src = "SELECT a FROM t;\nCREATE INDEX idx ON t (a);\nUPDATE t SET a = 1;";
+
+        let (result, warnings) =
+            format_sql_with_report(src, Some(r#"{"best_effort": true}"#), None)?;
+
+        assert!(result.contains("\nCREATE INDEX idx ON t (a);\n"));
+        assert!(result.ends_with("update\n\tt\nset\n\ta\t=\t1\n;\n"));
+        let unsupported = warnings
+            .iter()
+            .filter(|w| w.kind == WarningKind::UnsupportedStatement)
+            .collect::<Vec<_>>();
+        assert_eq!(unsupported.len(), 1);
+        assert_eq!(unsupported[0].span.start_line, 1);
+
+        // DO ブロックも、そのまま出力した文として報告する
+        let src = "SELECT a FROM t;\nDO $$ BEGIN END $$;";
+        let (_, warnings) = format_sql_with_report(src, None, None)?;
+        assert!(warnings
+            .iter()
+            .any(|w| { w.kind == WarningKind::UnsupportedStatement && w.span.start_line == 1 }));
+
+        Ok(())
+    }
+
+    fn report_complemented_keywords() -> Result<(), UroboroSQLFmtError> {
+        let src = "SELECT a\nFROM t LEFT JOIN u ON t.id = u.id";
+
+        let (_, warnings) = format_sql_with_report(src, None, None)?;
+
+        assert!(warnings.contains(&Warning {
+            kind: WarningKind::KeywordComplemented,
+            message: "alias was complemented".to_string(),
+            span: Span {
+                start_line: 0,
+                start_col: 7,
+                end_line: 0,
+                end_col: 8,
+            },
+        }));
+        assert!(warnings
+            .iter()
+            .any(|w| w.message == "OUTER keyword was complemented" && w.span.start_line == 1));
+
+        Ok(())
+    }
+
+    fn align_trailing_comments_to_column() -> Result<(), UroboroSQLFmtError> {
+        let src = "SELECT a -- x\n, bb -- y\nFROM t";
+
+        let result = format_sql(src, Some(r#"{"trailing_comment_column": 30}"#), None)?;
+
+        assert!(result.contains(&format!("\ta\tas\ta{}-- x\n", " ".repeat(17))));
+        assert!(result.contains(&format!(",\tbb\tas\tbb{}-- y\n", " ".repeat(16))));
+
+        // 句のキーワードの行やセパレータの行の行末コメントも、指定した列に揃える
+        let src = "SELECT a FROM t WHERE a = 1 AND -- w\nb = 2 LIMIT 10 -- z";
+
+        let result = format_sql(src, Some(r#"{"trailing_comment_column": 30}"#), None)?;
+
+        assert!(result.contains(&format!("\nand{}-- w\n", " ".repeat(27))));
+        assert!(result.contains(&format!("\nlimit\t10{}-- z\n", " ".repeat(20))));
+
+        Ok(())
+    }
+
+    fn report_positions_in_later_segment() -> Result<(), UroboroSQLFmtError> {
+        let src = "DO $$ BEGIN END $$; SELECT a\nFROM t LEFT JOIN u ON t.id = u.id;";
+
+        let (_, warnings) = format_sql_with_report(src, None, None)?;
+
+        // DO ブロックの後の文の警告も、ソース全体における位置で報告する
+        assert!(warnings.contains(&Warning {
+            kind: WarningKind::KeywordComplemented,
+            message: "alias was complemented".to_string(),
+            span: Span {
+                start_line: 0,
+                start_col: 27,
+                end_line: 0,
+                end_col: 28,
+            },
+        }));
+        assert!(warnings
+            .iter()
+            .any(|w| w.message == "OUTER keyword was complemented" && w.span.start_line == 1));
+
+        Ok(())
+    }
+
+    fn report_unknown_config_keys() -> Result<(), UroboroSQLFmtError> {
+        let (_, warnings) = format_sql_with_report(
+            "SELECT a FROM t",
+            Some(r#"{"keywrd_case": "upper", "foo": 1}"#),
+            None,
+        )?;
+
+        let messages = warnings
+            .iter()
+            .filter(|w| w.kind == WarningKind::UnknownConfigKey)
+            .map(|w| w.message.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            vec![
+                "unknown configuration key `foo`",
+                "unknown configuration key `keywrd_case`. Did you mean `keyword_case`?",
+            ]
+        );
+
+        Ok(())
+    }
+
+    fn complement_column_alias_by_naming() -> Result<(), UroboroSQLFmtError> {
+        let src = "SELECT count(*), max(t.amount), CAST(t.code AS int), t.id::text FROM t";
+
+        let result = format_sql(
+            src,
+            Some(r#"{"complement_column_alias": "postgresql"}"#),
+            None,
+        )?;
+        for alias in ["count", "max", "code", "id"] {
+            assert!(result.contains(&format!("\t{alias}\n")));
+        }
+
+        let result = format_sql(
+            src,
+            Some(r#"{"complement_column_alias": "snake_case"}"#),
+            None,
+        )?;
+        assert!(result.contains("\tmax_amount\n"));
+
+        // リテラルやキーワードの引数はカラム名に含めない
+        let result = format_sql(
+            "SELECT round(t.x, 1.5), coalesce(t.y, NULL, 'z') FROM t",
+            Some(r#"{"complement_column_alias": "snake_case"}"#),
+            None,
+        )?;
+        assert!(result.contains("\tround_x\n"));
+        assert!(result.contains("\tcoalesce_y\n"));
+
+        // Primary式のエイリアス補完は complement_alias の設定に従い、命名方法の影響を受けない
+        let src = "SELECT NULL, TRUE, current_date, 1.5, t.id FROM t";
+        assert_eq!(
+            format_sql(
+                src,
+                Some(r#"{"complement_column_alias": "snake_case"}"#),
+                None
+            )?,
+            format_sql(src, None, None)?
+        );
+
+        Ok(())
+    }
+
+    fn break_in_list_by_element_count() -> Result<(), UroboroSQLFmtError> {
+        let src = "SELECT * FROM t WHERE t.id IN (1, 2, 3, 4)";
+
+        let result = format_sql(src, Some(r#"{"in_list_max_elements": 3}"#), None)?;
+        assert!(result.contains("in\t(\n\t\t1\n\t,\t2\n\t,\t3\n\t,\t4\n\t)"));
+
+        let result = format_sql(src, Some(r#"{"in_list_max_elements": 4}"#), None)?;
+        assert!(result.contains("in\t(1, 2, 3, 4)"));
+
+        Ok(())
+    }
+
+    fn break_in_list_by_line_length() -> Result<(), UroboroSQLFmtError> {
+        let src =
+            "SELECT * FROM tbl WHERE tbl.cd IN ('long_value_1', 'long_value_2', 'long_value_3')";
+        let single_line = "\ttbl.cd\tin\t('long_value_1', 'long_value_2', 'long_value_3')\n";
+
+        // 指定しない場合は折り返さない
+        assert!(format_sql(src, None, None)?.contains(single_line));
+
+        // 行の長さはインデントと演算子の列を含めて数える (4 + 12 + 48 = 64)
+        let result = format_sql(src, Some(r#"{"max_line_length": 63}"#), None)?;
+        assert!(result.contains(
+            "\ttbl.cd\tin\t(\n\t\t'long_value_1'\n\t,\t'long_value_2'\n\t,\t'long_value_3'\n\t)"
+        ));
+        let result = format_sql(src, Some(r#"{"max_line_length": 64}"#), None)?;
+        assert!(result.contains(single_line));
+
+        // 縦揃えした演算子の列から数える
+        let settings = Some(r#"{"max_line_length": 41}"#);
+        let result = format_sql("SELECT * FROM t WHERE t.cd IN ('a', 'b')", settings, None)?;
+        assert!(result.contains("\tin\t('a', 'b')\n"));
+        let result = format_sql(
+            "SELECT * FROM t WHERE t.long_long_column_name = 1 AND t.cd IN ('a', 'b')",
+            settings,
+            None,
+        )?;
+        assert!(result.contains("\tin\t(\n"));
+
+        Ok(())
+    }
+
+    fn normalize_bind_param_spacing() -> Result<(), UroboroSQLFmtError> {
+        let settings = Some(r#"{"normalize_bind_param": true}"#);
+
+        let src = "SELECT * FROM t WHERE t.id = /* id */1 AND t.name IN /* names */('a')";
+        let (result, warnings) = format_sql_with_report(src, settings, None)?;
+        assert!(result.contains("\t/*id*/1\n"));
+        assert!(result.contains("\t/*names*/('a')\n"));
+        assert!(warnings.is_empty());
+
+        // バインドパラメータ名として正しくないコメントは正規化せず、警告を報告する
+        let src = "SELECT * FROM t WHERE t.code = /* TODO fix */'x'";
+        let (result, warnings) = format_sql_with_report(src, settings, None)?;
+        assert!(result.contains("\t/* TODO fix */'x'\n"));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, WarningKind::BindParamNotNormalized);
+
+        // 値と隣接していないコメントはバインドパラメータとして扱わず、警告を報告する
+        let src = "SELECT * FROM t WHERE t.id IN (/* ids */ 1, 2)";
+        let (result, warnings) = format_sql_with_report(src, settings, None)?;
+        assert!(result.contains("/* ids */"));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, WarningKind::BindParamNotNormalized);
+
+        Ok(())
+    }
+
+    fn datetime_function_follows_keyword_case() -> Result<(), UroboroSQLFmtError> {
+        let src = "SELECT Current_Timestamp(3), Count(*) FROM t";
+
+        let result = format_sql(
+            src,
+            Some(r#"{"keyword_case": "upper", "function_case": "lower"}"#),
+            None,
+        )?;
+
+        assert!(result.contains("\tCURRENT_TIMESTAMP(3)\n"));
+        assert!(result.contains(",\tcount(*)\n"));
+
+        Ok(())
+    }
+
+    fn semicolon_at_end_of_line() -> Result<(), UroboroSQLFmtError> {
+        let settings = Some(r#"{"semicolon_position": "end_of_line"}"#);
+
+        // 文字列中の `--` は行コメントではないため、行末にセミコロンを置く
+        let result = format_sql("SELECT a FROM t WHERE t.b = '--';", settings, None)?;
+        assert!(result.ends_with("\tt.b\t=\t'--';\n"));
+
+        // 文が行コメントで終わる場合は、セミコロンを次の行に置く
+        let result = format_sql("SELECT a FROM t -- c\n;", settings, None)?;
+        assert!(result.ends_with("\tt\t-- c\n;\n"));
+
+        Ok(())
+    }
+
+    fn reject_alias_after_using() -> Result<(), UroboroSQLFmtError> {
+        // USING の後の別名はサポートしていないため、別名を消さずにエラーとする
+        assert!(format_sql("SELECT * FROM t1 JOIN t2 USING (num) AS j", None, None).is_err());
+
+        Ok(())
+    }
+
+    fn report_deprecated_config_keys() -> Result<(), UroboroSQLFmtError> {
+        let (_, warnings) = format_sql_with_report(
+            "SELECT a FROM t",
+            Some(r#"{"remove_redundant_parentheses": false}"#),
+            None,
+        )?;
+
+        assert_eq!(
+            warnings
+                .iter()
+                .filter(|w| matches!(
+                    w.kind,
+                    WarningKind::DeprecatedConfigKey | WarningKind::UnknownConfigKey
+                ))
+                .collect::<Vec<_>>(),
+            vec![&Warning {
+                kind: WarningKind::DeprecatedConfigKey,
+                message: "configuration key `remove_redundant_parentheses` is deprecated. Use `remove_redundant_nest` instead.".to_string(),
+                span: Span::default(),
+            }]
+        );
+
+        Ok(())
+    }
+
+    fn report_format_marker_inside_statement() -> Result<(), UroboroSQLFmtError> {
+        let src =
+            "SELECT\n-- uroborosql-fmt:off\n  a,b\n-- uroborosql-fmt:on\nFROM t;\nSELECT c FROM t;";
+
+        let (result, warnings) = format_sql_with_report(src, None, None)?;
+
+        // 指示を含む文は文全体をそのまま出力し、その後の文はフォーマットする
+        assert!(result.starts_with(
+            "SELECT\n-- uroborosql-fmt:off\n  a,b\n-- uroborosql-fmt:on\nFROM t;\nselect\n"
+        ));
+        let lines = warnings
+            .iter()
+            .filter(|w| w.kind == WarningKind::MisplacedFormatMarker)
+            .map(|w| w.span.start_line)
+            .collect::<Vec<_>>();
+        assert_eq!(lines, vec![1, 3]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,542 @@
+//! フォーマット対象のソースを、通常通りフォーマットする部分とそのまま出力する部分に分割する。
+//!
+//! tree-sitter-sql でパースできない文 (DO ブロックなど) や、
+//! `-- uroborosql-fmt:off` と `-- uroborosql-fmt:on` で囲まれた範囲が含まれていても、
+//! その前後の文はフォーマットできるようにするために使用する。
+
+/// ソースを分割した断片
+#[derive(Debug, PartialEq)]
+pub(crate) enum Segment<'a> {
+    /// 通常通りフォーマットする SQL
+    Sql {
+        text: &'a str,
+        /// ソース全体における開始位置 (バイト単位)
+        offset: usize,
+    },
+    /// 変更せずにそのまま出力するテキスト
+    Verbatim {
+        text: &'a str,
+        /// ソース全体における開始位置 (バイト単位)
+        offset: usize,
+    },
+}
+
+impl Segment<'_> {
+    /// 前後の空白を除いたセグメントの内容の、ソース全体における範囲 (開始位置, 終了位置) を返す
+    pub(crate) fn content_range(&self) -> (usize, usize) {
+        let (Segment::Sql { text, offset } | Segment::Verbatim { text, offset }) = self;
+        let start = offset + (text.len() - text.trim_start().len());
+        let end = offset + text.trim_end().len();
+
+        (start, end)
+    }
+}
+
+/// ソースをセグメントに分割する。
+/// そのまま出力する文や範囲が含まれない場合は、ソース全体を一つの `Segment::Sql` として返す。
+pub(crate) fn split_segments(src: &str) -> Vec<Segment<'_>> {
+    let statements = statement_ranges(src);
+    // そのまま出力する範囲
+    let mut verbatim_ranges = widen_to_statements(format_off_ranges(src), &statements);
+
+    for &(start, end) in &statements {
+        let in_format_off = verbatim_ranges
+            .iter()
+            .any(|&(off_start, off_end)| start < off_end && off_start < end);
+
+        if is_do_block(&src[start..end]) && !in_format_off {
+            verbatim_ranges.push((start, end));
+        }
+    }
+
+    if verbatim_ranges.is_empty() {
+        return vec![Segment::Sql {
+            text: src,
+            offset: 0,
+        }];
+    }
+
+    verbatim_ranges.sort_unstable();
+    // 文全体まで広げた範囲は重なる場合があるため、結合する
+    verbatim_ranges.dedup_by(|next, prev| {
+        if next.0 < prev.1 {
+            prev.1 = prev.1.max(next.1);
+            true
+        } else {
+            false
+        }
+    });
+
+    let mut segments = vec![];
+    // 次の Sql セグメントの開始位置
+    let mut sql_start = 0;
+
+    for (start, end) in verbatim_ranges {
+        push_sql_segment(&mut segments, src, sql_start, start);
+        segments.push(Segment::Verbatim {
+            text: src[start..end].trim_end(),
+            offset: start,
+        });
+        sql_start = end;
+    }
+
+    push_sql_segment(&mut segments, src, sql_start, src.len());
+
+    segments
+}
+
+/// ソースの先頭に `-- uroborosql-fmt:disable` がある場合 true を返す
+pub(crate) fn is_format_disabled(src: &str) -> bool {
+    src.trim_start().lines().next().and_then(directive) == Some("disable")
+}
+
+/// フォーマットを行わない範囲 (`-- uroborosql-fmt:off` の行から `-- uroborosql-fmt:on` の行まで) を返す。
+/// `-- uroborosql-fmt:on` が存在しない場合は、ソースの末尾までを範囲とする。
+/// 行の途中に書かれたコメントは指示として扱わない。
+fn format_off_ranges(src: &str) -> Vec<(usize, usize)> {
+    let bytes = src.as_bytes();
+    let mut ranges = vec![];
+    // フォーマットを行わない範囲の開始位置
+    let mut off_start = None;
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'-' if bytes[pos..].starts_with(b"--") => {
+                let end = comment_end(bytes, pos).unwrap_or(bytes.len());
+                let line_start = src[..pos].rfind('\n').map_or(0, |i| i + 1);
+
+                if src[line_start..pos].trim().is_empty() {
+                    match (directive(&src[pos..end]), off_start) {
+                        (Some("off"), None) => off_start = Some(line_start),
+                        (Some("on"), Some(start)) => {
+                            ranges.push((start, end));
+                            off_start = None;
+                        }
+                        _ => {}
+                    }
+                }
+
+                pos = end;
+            }
+            b'/' if bytes[pos..].starts_with(b"/*") => pos = block_comment_end(bytes, pos),
+            quote @ (b'\'' | b'"') => pos = quoted_end(bytes, pos, quote),
+            b'$' => pos = dollar_quoted_end(bytes, pos),
+            _ => pos += 1,
+        }
+    }
+
+    if let Some(start) = off_start {
+        ranges.push((start, bytes.len()));
+    }
+
+    ranges
+}
+
+/// フォーマットを行わない範囲の境界が文の途中にある場合、その文全体を含むように範囲を広げて返す。
+/// 文の一部だけをそのまま出力し、残りをフォーマットすることはできないため。
+fn widen_to_statements(
+    ranges: Vec<(usize, usize)>,
+    statements: &[(usize, usize)],
+) -> Vec<(usize, usize)> {
+    ranges
+        .into_iter()
+        .map(|(mut start, mut end)| {
+            for &(stmt_start, stmt_end) in statements {
+                if stmt_start < start && start < stmt_end {
+                    start = stmt_start;
+                }
+                if stmt_start < end && end < stmt_end {
+                    end = stmt_end;
+                }
+            }
+            (start, end)
+        })
+        .collect()
+}
+
+/// 文の途中に書かれた `-- uroborosql-fmt:off` と `-- uroborosql-fmt:on` の行の範囲を返す。
+/// これらの指示を含む文は、文全体をそのまま出力する。
+pub(crate) fn misplaced_format_markers(src: &str) -> Vec<(usize, usize)> {
+    let statements = statement_ranges(src);
+    let is_inside_statement = |pos: usize| {
+        statements
+            .iter()
+            .any(|&(stmt_start, stmt_end)| stmt_start < pos && pos < stmt_end)
+    };
+    // pos を含む行の範囲
+    let line_range = |pos: usize| {
+        let start = src[..pos].rfind('\n').map_or(0, |i| i + 1);
+        let end = src[pos..].find('\n').map_or(src.len(), |i| pos + i);
+        (start, end)
+    };
+
+    let mut markers = vec![];
+    for (start, end) in format_off_ranges(src) {
+        // start は off の行の先頭、end は on のコメントの終了位置
+        if is_inside_statement(start) {
+            markers.push(line_range(start));
+        }
+        if is_inside_statement(end) {
+            markers.push(line_range(end));
+        }
+    }
+
+    markers
+}
+
+/// 行コメントが `-- uroborosql-fmt:<指示>` の形式である場合、指示を返す
+fn directive(comment: &str) -> Option<&str> {
+    comment
+        .trim()
+        .strip_prefix("--")?
+        .trim()
+        .strip_prefix("uroborosql-fmt:")
+        .map(str::trim)
+}
+
+/// ソースの start から end までを Sql セグメントとして追加する。
+/// 空白のみの場合は追加せず、コメントのみの場合はそのまま出力するセグメントとして追加する。
+fn push_sql_segment<'a>(segments: &mut Vec<Segment<'a>>, src: &'a str, start: usize, end: usize) {
+    let sql = &src[start..end];
+    let trimmed = sql.trim();
+
+    if trimmed.is_empty() {
+        return;
+    }
+
+    if skip_trivia(sql.as_bytes(), 0) == sql.len() {
+        // 文を含まない部分は tree-sitter-sql に渡すことができないため、そのまま出力する
+        segments.push(Segment::Verbatim {
+            text: trimmed,
+            offset: start + (sql.len() - sql.trim_start().len()),
+        });
+    } else {
+        segments.push(Segment::Sql {
+            text: sql,
+            offset: start,
+        });
+    }
+}
+
+/// ソースの offset バイト目から始まる断片 text を、ソース全体と同じ行と列に配置した文字列を返す。
+/// 断片より前の部分は、改行を残して同じバイト数の空白に置き換える。
+/// 断片をパースした際のエラーや警告の位置を、ソース全体における位置とするために使用する。
+pub(crate) fn place_at_offset(src: &str, offset: usize, text: &str) -> String {
+    let mut result = String::with_capacity(offset + text.len());
+
+    for c in src[..offset].chars() {
+        if c == '\n' {
+            result.push('\n');
+        } else {
+            result.extend(std::iter::repeat_n(' ', c.len_utf8()));
+        }
+    }
+
+    result.push_str(text);
+    result
+}
+
+/// 文が DO ブロック (`DO $$ ... $$;`) であるかどうかを判定する
+pub(crate) fn is_do_block(stmt: &str) -> bool {
+    let bytes = stmt.as_bytes();
+
+    bytes.len() > 2
+        && bytes[..2].eq_ignore_ascii_case(b"do")
+        && (bytes[2].is_ascii_whitespace() || bytes[2] == b'$')
+}
+
+/// ソース中の各文の範囲 (先頭のコメントを除いた開始位置, セミコロンの直後の位置) を返す。
+/// 文字列リテラル、引用符付き識別子、ドル引用符付き文字列、コメント中のセミコロンは区切りとみなさない。
+pub(crate) fn statement_ranges(src: &str) -> Vec<(usize, usize)> {
+    let bytes = src.as_bytes();
+    let mut ranges = vec![];
+
+    let mut pos = skip_trivia(bytes, 0);
+    let mut start = pos;
+
+    while pos < bytes.len() {
+        if let Some(end) = comment_end(bytes, pos) {
+            pos = end;
+            continue;
+        }
+
+        match bytes[pos] {
+            b';' => {
+                ranges.push((start, pos + 1));
+                pos = skip_trivia(bytes, pos + 1);
+                start = pos;
+            }
+            quote @ (b'\'' | b'"') => pos = quoted_end(bytes, pos, quote),
+            b'$' => pos = dollar_quoted_end(bytes, pos),
+            _ => pos += 1,
+        }
+    }
+
+    if start < bytes.len() {
+        ranges.push((start, bytes.len()));
+    }
+
+    ranges
+}
+
+/// 空白とコメントを読み飛ばした位置を返す
+fn skip_trivia(bytes: &[u8], mut pos: usize) -> usize {
+    loop {
+        while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+
+        match comment_end(bytes, pos) {
+            Some(end) => pos = end,
+            None => return pos,
+        }
+    }
+}
+
+/// pos がコメントの開始位置である場合、コメントの終了位置を返す
+fn comment_end(bytes: &[u8], pos: usize) -> Option<usize> {
+    if bytes[pos..].starts_with(b"--") {
+        let end = bytes[pos..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(bytes.len(), |i| pos + i);
+        Some(end)
+    } else if bytes[pos..].starts_with(b"/*") {
+        Some(block_comment_end(bytes, pos))
+    } else {
+        None
+    }
+}
+
+/// ブロックコメントの終了位置を返す。
+/// tree-sitter-sql の文法と同様に、最初の `*/` でコメントが終了するものとする。
+/// 入れ子になったブロックコメント (`/* ... /* ... */ ... */`) は文法が対応していないため考慮しない。
+fn block_comment_end(bytes: &[u8], pos: usize) -> usize {
+    bytes[pos + 2..]
+        .windows(2)
+        .position(|w| w == b"*/")
+        .map_or(bytes.len(), |i| pos + 2 + i + 2)
+}
+
+/// 引用符で囲まれた部分の終了位置を返す。
+/// 引用符を2つ重ねたエスケープと、エスケープ文字列定数 (`E'...'`) 中のバックスラッシュによるエスケープを考慮する。
+fn quoted_end(bytes: &[u8], pos: usize, quote: u8) -> usize {
+    let is_escape_string = quote == b'\'' && is_escape_string_prefix(bytes, pos);
+    let mut i = pos + 1;
+
+    while i < bytes.len() {
+        if is_escape_string && bytes[i] == b'\\' {
+            i += 2;
+            continue;
+        }
+
+        if bytes[i] == quote {
+            if bytes.get(i + 1) == Some(&quote) {
+                i += 2;
+                continue;
+            }
+            return i + 1;
+        }
+        i += 1;
+    }
+
+    bytes.len()
+}
+
+/// pos の引用符の直前が、エスケープ文字列定数の接頭辞 `E` (または `e`) である場合 true を返す。
+/// `type'...'` のように識別子の末尾が `e` である場合は接頭辞とみなさない。
+fn is_escape_string_prefix(bytes: &[u8], pos: usize) -> bool {
+    let is_identifier_char = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+
+    pos >= 1
+        && bytes[pos - 1].eq_ignore_ascii_case(&b'e')
+        && (pos == 1 || !is_identifier_char(bytes[pos - 2]))
+}
+
+/// ドル引用符付き文字列 (`$tag$ ... $tag$`) の終了位置を返す。
+/// バインドパラメータ (`$1`) などドル引用符でない場合は、次の位置を返す。
+fn dollar_quoted_end(bytes: &[u8], pos: usize) -> usize {
+    let mut i = pos + 1;
+
+    if matches!(bytes.get(i), Some(b) if b.is_ascii_digit()) {
+        return pos + 1;
+    }
+
+    while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+        i += 1;
+    }
+
+    if bytes.get(i) != Some(&b'$') {
+        return pos + 1;
+    }
+
+    let tag = &bytes[pos..=i];
+    let body_start = i + 1;
+
+    bytes[body_start..]
+        .windows(tag.len())
+        .position(|w| w == tag)
+        .map_or(bytes.len(), |j| body_start + j + tag.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 各文の範囲のテキストを返す
+    fn statements(src: &str) -> Vec<&str> {
+        statement_ranges(src)
+            .into_iter()
+            .map(|(start, end)| &src[start..end])
+            .collect()
+    }
+
+    #[test]
+    fn split_statements_by_semicolon() {
+        assert_eq!(
+            statements("SELECT 1;\nSELECT 2;\nSELECT 3"),
+            vec!["SELECT 1;", "SELECT 2;", "SELECT 3"]
+        );
+    }
+
+    #[test]
+    fn ignore_semicolon_in_dollar_quoted_string() {
+        let src = "DO $body$ BEGIN PERFORM 1; END $body$;\nSELECT $1;\nSELECT $$a;b$$;";
+
+        assert_eq!(
+            statements(src),
+            vec![
+                "DO $body$ BEGIN PERFORM 1; END $body$;",
+                "SELECT $1;",
+                "SELECT $$a;b$$;",
+            ]
+        );
+    }
+
+    #[test]
+    fn ignore_semicolon_in_escape_string() {
+        let src = "SELECT E'it\\'s; x';\nSELECT e'\\\\';\nSELECT 'a\\';";
+
+        assert_eq!(
+            statements(src),
+            vec!["SELECT E'it\\'s; x';", "SELECT e'\\\\';", "SELECT 'a\\';"]
+        );
+    }
+
+    #[test]
+    fn backslash_is_not_escape_in_standard_string() {
+        // 識別子の末尾の e は接頭辞とみなさない
+        let src = "SELECT type'a\\';\nSELECT 1;";
+
+        assert_eq!(statements(src), vec!["SELECT type'a\\';", "SELECT 1;"]);
+    }
+
+    #[test]
+    fn ignore_semicolon_in_comments() {
+        let src = "-- a; b\nSELECT /* c; d */ 1;\nSELECT 2; -- e;";
+
+        assert_eq!(statements(src), vec!["SELECT /* c; d */ 1;", "SELECT 2;"]);
+    }
+
+    #[test]
+    fn block_comment_ends_at_first_terminator() {
+        assert_eq!(
+            statements("SELECT /* a /* b; */ 1;\nSELECT 2;"),
+            vec!["SELECT /* a /* b; */ 1;", "SELECT 2;"]
+        );
+
+        // 入れ子のブロックコメントは意図的にサポートしない (文法と同様に最初の `*/` で終了する)
+        // そのため、内側の `*/` の後のセミコロンで文が区切られる
+        assert_eq!(
+            statements("SELECT 1 /* a /* b */; SELECT 2 */;"),
+            vec!["SELECT 1 /* a /* b */;", "SELECT 2 */;"]
+        );
+    }
+
+    #[test]
+    fn split_do_block_and_format_off_range() {
+        let src = "SELECT 1;\nDO $$ BEGIN END $$;\n-- uroborosql-fmt:off\nSELECT  2;\n-- uroborosql-fmt:on\nSELECT 3;";
+
+        assert_eq!(
+            split_segments(src),
+            vec![
+                Segment::Sql {
+                    text: "SELECT 1;\n",
+                    offset: 0,
+                },
+                Segment::Verbatim {
+                    text: "DO $$ BEGIN END $$;",
+                    offset: 10,
+                },
+                Segment::Verbatim {
+                    text: "-- uroborosql-fmt:off\nSELECT  2;\n-- uroborosql-fmt:on",
+                    offset: 30,
+                },
+                Segment::Sql {
+                    text: "\nSELECT 3;",
+                    offset: 83,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn widen_format_off_range_to_whole_statements() {
+        let src = "SELECT 1;\nSELECT\n-- uroborosql-fmt:off\n  a,b\n-- uroborosql-fmt:on\nFROM t;\nSELECT 3;";
+
+        assert_eq!(
+            split_segments(src),
+            vec![
+                Segment::Sql {
+                    text: "SELECT 1;\n",
+                    offset: 0,
+                },
+                Segment::Verbatim {
+                    text: "SELECT\n-- uroborosql-fmt:off\n  a,b\n-- uroborosql-fmt:on\nFROM t;",
+                    offset: 10,
+                },
+                Segment::Sql {
+                    text: "\nSELECT 3;",
+                    offset: 73,
+                },
+            ]
+        );
+        assert_eq!(
+            misplaced_format_markers(src)
+                .into_iter()
+                .map(|(start, end)| &src[start..end])
+                .collect::<Vec<_>>(),
+            vec!["-- uroborosql-fmt:off", "-- uroborosql-fmt:on"]
+        );
+
+        // 文の境界にある場合は報告しない
+        let src = "SELECT 1;\n-- uroborosql-fmt:off\nSELECT  2;\n-- uroborosql-fmt:on\nSELECT 3;";
+        assert!(misplaced_format_markers(src).is_empty());
+    }
+
+    #[test]
+    fn content_range_excludes_surrounding_whitespace() {
+        let src = "SELECT 1;\n\nDO $$ BEGIN END $$;\n\n\nSELECT 2;\n";
+        let ranges = split_segments(src)
+            .iter()
+            .map(Segment::content_range)
+            .collect::<Vec<_>>();
+
+        assert_eq!(ranges, vec![(0, 9), (11, 30), (33, 42)]);
+    }
+
+    #[test]
+    fn place_segment_at_same_position_as_source() {
+        let src = "DO $$ BEGIN END $$;\n-- あ\n  SELECT 1;";
+        let offset = src.find("SELECT").unwrap();
+
+        let placed = place_at_offset(src, offset, &src[offset..]);
+
+        // 行と列 (バイト数) がソース全体と一致する
+        assert_eq!(placed.len(), src.len());
+        assert_eq!(placed.lines().count(), src.lines().count());
+        assert_eq!(placed.lines().nth(2), Some("  SELECT 1;"));
+        assert!(placed[..offset].trim().is_empty());
+    }
+}
@@ -11,6 +11,58 @@ pub(crate) fn convert_keyword_case(keyword: &str) -> String {
     CONFIG.read().unwrap().keyword_case.format(keyword)
 }
 
+/// 設定ファイルに合わせて関数名の大文字・小文字を変換する
+/// function_case が指定されていない場合は keyword_case に従う
+pub(crate) fn convert_function_case(name: &str) -> String {
+    let config = CONFIG.read().unwrap();
+    config
+        .function_case
+        .as_ref()
+        .unwrap_or(&config.keyword_case)
+        .format(name)
+}
+
+/// 設定ファイルに合わせて型名の大文字・小文字を変換する
+/// type_case が指定されていない場合は keyword_case に従う
+pub(crate) fn convert_type_case(type_name: &str) -> String {
+    let config = CONFIG.read().unwrap();
+    config
+        .type_case
+        .as_ref()
+        .unwrap_or(&config.keyword_case)
+        .format(type_name)
+}
+
+/// 型名の空白を正規化する
+/// 連続する空白は1つにまとめ、括弧の前後とカンマの前の空白は取り除き、カンマの後には空白を1つ置く
+///
+/// 例: "CHAR   ( 3    )" => "CHAR(3)", "NUMERIC ( 10 ,2 )" => "NUMERIC(10, 2)"
+pub(crate) fn normalize_type_name(type_name: &str) -> String {
+    let mut result = String::new();
+    // 次の文字の前に空白を置くかどうか
+    let mut pending_space = false;
+
+    for c in type_name.chars() {
+        match c {
+            c if c.is_whitespace() => pending_space = true,
+            '(' | ')' | '[' | ']' | ',' => {
+                result.push(c);
+                // カンマの後には空白を置き、括弧の後の空白は取り除く
+                pending_space = c == ',';
+            }
+            _ => {
+                if pending_space && !result.is_empty() && !result.ends_with(['(', '[']) {
+                    result.push(' ');
+                }
+                result.push(c);
+                pending_space = false;
+            }
+        }
+    }
+
+    result
+}
+
 /// 引数の文字列が識別子であれば設定ファイルに合わせて大文字小文字変換をして返す
 /// 文字列リテラル、または引用符付き識別子である場合はそのままの文字列を返す
 pub(crate) fn convert_identifier_case(identifier: &str) -> String {
@@ -23,12 +75,26 @@ pub(crate) fn convert_identifier_case(identifier: &str) -> String {
 
 /// 引数の文字列が引用符付けされているかどうかを判定する。
 /// 引用符付けされている場合は true を返す。
+/// エスケープ文字列 (E'...') と Unicode エスケープ (U&'...', U&"...") の接頭辞も引用符の一部として扱う。
 pub(crate) fn is_quoted(elem: &str) -> bool {
+    let elem = strip_literal_prefix(elem);
+
     (elem.starts_with('"') && elem.ends_with('"'))
         || (elem.starts_with('\'') && elem.ends_with('\''))
         || (elem.starts_with('$') && elem.ends_with('$'))
 }
 
+/// 文字列リテラルの接頭辞 (E, U&) を取り除いた文字列を返す
+fn strip_literal_prefix(elem: &str) -> &str {
+    for prefix in ["E'", "e'", "U&'", "u&'", "U&\"", "u&\""] {
+        if elem.starts_with(prefix) {
+            // 引用符は残す
+            return &elem[prefix.len() - 1..];
+        }
+    }
+    elem
+}
+
 /// 引数の文字列長をタブ数換算した長さを返す
 ///
 /// 例えばtabsize = 4の場合
@@ -66,6 +132,30 @@ pub(crate) fn trim_bind_param(text: String) -> String {
     }
 }
 
+/// 設定の normalize_bind_param が true であり、バインドパラメータ名が正しい形であるとき、
+/// 引数のバインドパラメータを空白を含まない `/*param*/` の形に正規化して返す。
+/// それ以外の場合は、引数をそのまま返す。
+pub(crate) fn normalize_bind_param(text: String) -> String {
+    if CONFIG.read().unwrap().normalize_bind_param && is_bind_param_name(&text) {
+        format!(
+            "/*{}*/",
+            text.trim_start_matches("/*").trim_end_matches("*/").trim()
+        )
+    } else {
+        text
+    }
+}
+
+/// ブロックコメントの内容が、前後の空白を除いて空白を含まない識別子 (`.` による区切りを含む) であれば true を返す
+pub(crate) fn is_bind_param_name(text: &str) -> bool {
+    let name = text.trim_start_matches("/*").trim_end_matches("*/").trim();
+
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+}
+
 /// 引数が定義ファイルで設定した1行の文字数上限を超えていた場合 true を返す
 pub(crate) fn is_line_overflow(char_len: usize) -> bool {
     // 1行当たりの上限文字数
@@ -79,6 +169,23 @@ pub(crate) fn is_line_overflow(char_len: usize) -> bool {
     }
 }
 
+/// IN のリストの要素数または幅が、定義ファイルで設定した上限を超えていた場合 true を返す
+pub(crate) fn is_in_list_overflow(elements: usize, width: usize) -> bool {
+    let config = CONFIG.read().unwrap();
+
+    config
+        .in_list_max_elements
+        .is_some_and(|max_elements| elements > max_elements)
+        || config
+            .in_list_max_width
+            .is_some_and(|max_width| width > max_width)
+}
+
+/// 設定から IN のリストを含む行の長さの上限を取得する
+pub(crate) fn max_line_length() -> Option<usize> {
+    CONFIG.read().unwrap().max_line_length
+}
+
 /// xバイト目が何文字目かを返す
 fn byte_to_char_index(input: &str, target_byte_index: usize) -> Result<usize, UroboroSQLFmtError> {
     let mut char_index = 0;
@@ -201,6 +308,59 @@ pub(crate) fn add_space_by_range(result: &mut String, start_col: usize, end_col:
     }
 }
 
+/// 設定から行末コメントを揃える列を取得する
+pub(crate) fn trailing_comment_column() -> Option<usize> {
+    CONFIG.read().unwrap().trailing_comment_column
+}
+
+/// 行末コメントを追加する。
+/// 揃える列が設定で指定されている場合はその列に、指定されていない場合は空白を1つ挟んで追加する。
+/// start_col には result の1行目が描画される列を与える。
+pub(crate) fn add_trailing_comment(result: &mut String, start_col: usize, comment: &str) {
+    if let Some(column) = trailing_comment_column() {
+        add_comment_at_column(result, start_col, comment, column);
+    } else {
+        add_single_space(result);
+        result.push_str(comment);
+    }
+}
+
+/// 行末コメントを column 列目から始まるようにスペースで埋めて追加する。
+/// 既に column 列目を超えている場合は、スペースを1つ挟んで追加する。
+/// start_col には result の1行目が描画される列を与える。
+pub(crate) fn add_comment_at_column(
+    result: &mut String,
+    start_col: usize,
+    comment: &str,
+    column: usize,
+) {
+    let end_col = last_line_end_col(result, start_col);
+    let padding = column.saturating_sub(end_col).max(1);
+
+    result.push_str(&" ".repeat(padding));
+    result.push_str(comment);
+}
+
+/// start_col 列目から文字列を描画した際に、最後の行の末尾が何列目になるかを返す
+/// タブ文字は次のタブ幅の倍数の列まで進むものとして計算する
+pub(crate) fn last_line_end_col(s: &str, start_col: usize) -> usize {
+    let (mut col, last_line) = match s.rfind('\n') {
+        Some(i) => (0, &s[i + 1..]),
+        None => (start_col, s),
+    };
+
+    let tab_size = tab_size();
+    for c in last_line.chars() {
+        if c == '\t' {
+            col = (col / tab_size + 1) * tab_size;
+        } else {
+            col += count_width(c.to_string());
+        }
+    }
+
+    col
+}
+
 /// 文字列の幅を計算する
 pub(crate) fn count_width(s: impl AsRef<str>) -> usize {
     s.as_ref()
@@ -2,16 +2,18 @@ mod clause;
 mod expr;
 mod statement;
 
+use itertools::Itertools;
 use tree_sitter::{Node, TreeCursor};
 
 pub(crate) const COMMENT: &str = "comment";
 pub(crate) const COMMA: &str = ",";
 
 use crate::{
-    config::CONFIG,
+    config::{ColumnAliasNaming, CONFIG},
     cst::*,
     error::UroboroSQLFmtError,
     util::{convert_identifier_case, create_error_annotation},
+    warning::{Span, Warning, WarningKind},
 };
 
 use self::expr::ComplementConfig;
@@ -19,6 +21,8 @@ use self::expr::ComplementConfig;
 pub(crate) struct Visitor {
     /// select文、insert文などが複数回出てきた際に1度だけSQL_IDを補完する、という処理を実現するためのフラグ
     should_complement_sql_id: bool,
+    /// フォーマット中に発生した警告
+    warnings: Vec<Warning>,
 }
 
 impl Default for Visitor {
@@ -32,9 +36,52 @@ impl Visitor {
     pub(crate) fn new() -> Visitor {
         Visitor {
             should_complement_sql_id: CONFIG.read().unwrap().complement_sql_id,
+            warnings: vec![],
         }
     }
 
+    /// フォーマット中に発生した警告を取り出す
+    pub(crate) fn take_warnings(&mut self) -> Vec<Warning> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    /// 警告を追加する
+    pub(crate) fn add_warning(&mut self, kind: WarningKind, message: &str, loc: &Location) {
+        self.warnings
+            .push(Warning::new(kind, message, Span::from_location(loc)));
+    }
+
+    /// コメントが loc の式に付与するバインドパラメータであれば true を返す。
+    /// 設定の normalize_bind_param が true の場合、正規化しないバインドパラメータらしきコメントに対して警告を追加する。
+    fn check_bind_param(&mut self, comment: &Comment, loc: &Location) -> bool {
+        let is_bind_param = comment.is_bind_param_of(loc);
+
+        if CONFIG.read().unwrap().normalize_bind_param
+            && comment.is_block_comment()
+            && !comment.is_single_line_hint()
+            && !comment.is_two_way_sql_comment()
+        {
+            if is_bind_param && !comment.has_bind_param_name() {
+                self.add_warning(
+                    WarningKind::BindParamNotNormalized,
+                    "bind parameter was not normalized because its name is not valid",
+                    &comment.loc(),
+                );
+            } else if !is_bind_param
+                && comment.has_bind_param_name()
+                && comment.loc().is_same_line(loc)
+            {
+                self.add_warning(
+                    WarningKind::BindParamNotNormalized,
+                    "comment was not normalized as a bind parameter because it is not adjacent to the value",
+                    &comment.loc(),
+                );
+            }
+        }
+
+        is_bind_param
+    }
+
     /// sqlソースファイルをフォーマット用構造体に変形する
     pub(crate) fn visit_sql(
         &mut self,
@@ -74,9 +121,26 @@ impl Visitor {
         // 文を読んだが、対応するセミコロンを読んでいない場合はtrue、そうでない場合false
         let mut above_semi = true;
 
+        // 文と文、句と句の間に保持する空行の上限
+        let max_blank_lines = CONFIG.read().unwrap().preserve_blank_lines;
+        // 直前に読んだノードの終了行
+        let mut prev_end_row: Option<usize> = None;
+        // 次の文の前に保持する空行の数
+        let mut blank_lines_before = 0;
+
         loop {
             let kind = cursor.node().kind();
 
+            // 文の先頭 (文自体、または文の上のコメント) であれば、直前のノードとの間の空行を数える
+            let is_head_of_stmt = (kind.ends_with("_statement") || kind == COMMENT && !above_semi)
+                && comment_buf.is_empty();
+            if let Some(prev_end_row) = prev_end_row.filter(|_| is_head_of_stmt) {
+                let start_row = cursor.node().start_position().row;
+                blank_lines_before =
+                    count_blank_lines(src, prev_end_row, start_row).min(max_blank_lines);
+            }
+            prev_end_row = Some(cursor.node().end_position().row);
+
             if kind.ends_with("_statement") {
                 let mut stmt = match kind {
                     "select_statement" => self.visit_select_stmt(cursor, src)?,
@@ -99,6 +163,12 @@ impl Visitor {
                     .for_each(|c| stmt.add_comment(c));
                 comment_buf.clear();
 
+                if max_blank_lines > 0 {
+                    stmt.set_blank_lines_before(blank_lines_before);
+                    preserve_blank_lines_between_clauses(&mut stmt, src, max_blank_lines);
+                }
+                blank_lines_before = 0;
+
                 source.push(stmt);
                 above_semi = true;
             } else if kind == COMMENT {
@@ -116,6 +186,12 @@ impl Visitor {
                 above_semi = false;
                 if let Some(last) = source.last_mut() {
                     last.set_semi(true);
+                    // セミコロンの直前のノードが行コメントで終わる場合、その行コメントは文の最後の行の末尾に出力される
+                    let ends_with_line_comment = cursor
+                        .node()
+                        .prev_sibling()
+                        .is_some_and(|node| ends_with_line_comment(node, src));
+                    last.set_ends_with_line_comment(ends_with_line_comment);
                 }
                 // tree-sitter-sqlでは、;の上に文がない場合syntax errorになる
             }
@@ -169,6 +245,16 @@ impl Visitor {
                         let alias = self.visit_aliasable_expr(cursor, src, complement_config)?;
                         separated_lines.add_expr(alias, Some(COMMA.to_string()), vec![]);
                     } else {
+                        // カンマの直後の行末コメントは、カンマの前に移動して出力される
+                        if !comment.is_block_comment()
+                            && comment_node.prev_sibling().map(|n| n.kind()) == Some(COMMA)
+                        {
+                            self.add_warning(
+                                WarningKind::CommentMoved,
+                                "trailing comment after comma was moved before the comma",
+                                &comment.loc(),
+                            );
+                        }
                         separated_lines.add_comment_to_child(comment)?;
                     }
                 }
@@ -242,6 +328,19 @@ impl Visitor {
         Ok(Body::SepLines(separated_lines))
     }
 
+    /// カーソルが指すノードが単一行のヒント句 (`/*+ ... */`) であれば、clauseに追加する
+    /// ヒント句は一般のコメントとは異なり、キーワードと同じ行に固定して出力される
+    fn consume_hint(&mut self, cursor: &mut TreeCursor, src: &str, clause: &mut Clause) {
+        if cursor.node().kind() == COMMENT {
+            let comment = Comment::new(cursor.node(), src);
+
+            if comment.is_single_line_hint() {
+                clause.set_hint(comment);
+                cursor.goto_next_sibling();
+            }
+        }
+    }
+
     /// カーソルが指すノードがSQL_IDであれば、clauseに追加する
     /// もし (_SQL_ID_が存在していない) && (_SQL_ID_がまだ出現していない) && (_SQL_ID_の補完がオン)
     /// の場合は補完する
@@ -267,6 +366,11 @@ impl Visitor {
         if self.should_complement_sql_id {
             clause.set_sql_id(SqlID::new("/* _SQL_ID_ */".to_string()));
             self.should_complement_sql_id = false;
+            self.add_warning(
+                WarningKind::KeywordComplemented,
+                "SQL_ID was complemented",
+                &clause.loc(),
+            );
         }
     }
 
@@ -287,6 +391,41 @@ impl Visitor {
     }
 }
 
+/// 文の句と句の間の空行を、max_blank_lines を上限として保持する
+fn preserve_blank_lines_between_clauses(stmt: &mut Statement, src: &str, max_blank_lines: usize) {
+    let clauses = stmt.clauses_mut();
+
+    for i in 1..clauses.len() {
+        let prev_end_row = clauses[i - 1].loc().end_position.row;
+        let start_row = clauses[i].loc().start_position.row;
+
+        let blank_lines = count_blank_lines(src, prev_end_row, start_row).min(max_blank_lines);
+        clauses[i].set_blank_lines_before(blank_lines);
+    }
+}
+
+/// start_row 行目と end_row 行目の間 (両端を含まない) にある空行の数を返す
+fn count_blank_lines(src: &str, start_row: usize, end_row: usize) -> usize {
+    if end_row <= start_row {
+        return 0;
+    }
+
+    src.lines()
+        .skip(start_row + 1)
+        .take(end_row - start_row - 1)
+        .filter(|line| line.trim().is_empty())
+        .count()
+}
+
+/// ノードの最後の葉ノードが行コメントであればtrueを返す
+fn ends_with_line_comment(mut node: Node, src: &str) -> bool {
+    while node.child_count() > 0 {
+        node = node.child(node.child_count() - 1).unwrap();
+    }
+
+    node.kind() == COMMENT && !Comment::new(node, src).is_block_comment()
+}
+
 /// cursorが指定した種類のノードを指しているかどうかをチェックする関数
 /// 期待しているノードではない場合、エラーを返す
 fn ensure_kind<'a>(
@@ -325,6 +464,57 @@ fn create_alias(lhs: &Expr) -> Option<Expr> {
     }
 }
 
+/// 識別子以外の式のエイリアス補完を行う際に、naming に従ったエイリアス名を持つ Expr を生成する関数。
+/// 識別子である場合や、エイリアス名を生成できない場合は None を返す。
+fn create_column_alias(lhs: &Expr, naming: ColumnAliasNaming) -> Option<Expr> {
+    if matches!(lhs, Expr::Primary(_)) {
+        // 識別子のエイリアス補完は complement_alias の設定に従う
+        return None;
+    }
+
+    column_name(lhs, naming).map(|name| {
+        Expr::Primary(Box::new(PrimaryExpr::new(
+            convert_identifier_case(&name),
+            lhs.loc(),
+        )))
+    })
+}
+
+/// naming に従って、式から生成したカラム名を返す
+fn column_name(expr: &Expr, naming: ColumnAliasNaming) -> Option<String> {
+    match expr {
+        Expr::Primary(prim) if prim.is_column_name() => {
+            prim.element().split('.').last().map(str::to_string)
+        }
+        // CAST(X AS type) と X::type は X のカラム名
+        Expr::FunctionCall(func) if func.name().eq_ignore_ascii_case("CAST") => func
+            .args()
+            .exprs()
+            .first()
+            .and_then(|arg| column_name(arg.lhs(), naming)),
+        Expr::TypeCast(type_cast) => column_name(type_cast.expr(), naming),
+        Expr::FunctionCall(func) => {
+            // スキーマ修飾された関数名は関数名のみを使用する
+            let name = func.name().split('.').last()?.to_string();
+
+            match naming {
+                ColumnAliasNaming::Postgresql => Some(name),
+                ColumnAliasNaming::SnakeCase => {
+                    let arg_names = func
+                        .args()
+                        .exprs()
+                        .iter()
+                        .filter_map(|arg| column_name(arg.lhs(), naming));
+
+                    Some(std::iter::once(name).chain(arg_names).join("_"))
+                }
+            }
+        }
+        Expr::Cond(_) => Some("case".to_string()),
+        _ => None,
+    }
+}
+
 /// keyword の Clauseを生成する関数。
 /// 呼び出し後の cursor はキーワードの最後のノードを指す。
 /// cursor のノードがキーワードと異なっていたら UroboroSQLFmtErrorを返す。
@@ -4,7 +4,8 @@ use crate::{
     config::CONFIG,
     cst::*,
     error::UroboroSQLFmtError,
-    visitor::{create_clause, ensure_kind, error_annotation_from_cursor, Visitor},
+    visitor::{create_clause, ensure_kind, error_annotation_from_cursor, Visitor, COMMENT},
+    warning::WarningKind,
 };
 
 impl Visitor {
@@ -20,13 +21,18 @@ impl Visitor {
         let mut clauses: Vec<Clause> = vec![];
 
         let mut join_clause = if cursor.node().kind() == "join_type" {
-            let mut clause = self.visit_join_type(cursor, src)?;
+            let join_type = self.visit_join_type(cursor, src)?;
             cursor.goto_next_sibling();
 
             ensure_kind(cursor, "JOIN", src)?;
-            clause.extend_kw(cursor.node(), src);
-
-            clause
+            match join_type {
+                Some(mut clause) => {
+                    clause.extend_kw(cursor.node(), src);
+                    clause
+                }
+                // join_type のキーワードがすべて除去された場合
+                None => create_clause(cursor, src, "JOIN")?,
+            }
         } else {
             create_clause(cursor, src, "JOIN")?
         };
@@ -78,10 +84,31 @@ impl Visitor {
 
                 Ok(on_clause)
             }
-            "USING" => Err(UroboroSQLFmtError::Unimplemented(format!(
-                "visit_join_clause(): JOIN USING(...) is unimplemented\n{}",
-                error_annotation_from_cursor(cursor, src)
-            ))),
+            "USING" => {
+                let mut using_clause = create_clause(cursor, src, "USING")?;
+                cursor.goto_next_sibling();
+
+                self.consume_comment_in_clause(cursor, src, &mut using_clause)?;
+
+                // USING の後には "(" identifier ["," identifier ...] ")" が続く
+                let column_list = self.visit_column_list(cursor, src)?;
+
+                // `USING (...) AS alias` (join_using_alias) はサポートしていない
+                // 別名を出力せずに消してしまわないよう、エラーとする
+                if let Some(next) = cursor.node().next_sibling() {
+                    if next.kind() != COMMENT {
+                        return Err(UroboroSQLFmtError::Unimplemented(format!(
+                            "visit_join_condition(): alias after USING is not supported\n{}",
+                            error_annotation_from_cursor(cursor, src)
+                        )));
+                    }
+                }
+
+                let body = Body::from(Expr::ColumnList(Box::new(column_list)));
+                using_clause.set_body(body);
+
+                Ok(using_clause)
+            }
             _ => Err(UroboroSQLFmtError::Unimplemented(format!(
                 "visit_join_condition(): unimplemented node\n{}",
                 error_annotation_from_cursor(cursor, src)
@@ -100,11 +127,12 @@ impl Visitor {
     ///
     /// 例えば、JOIN句 が ".. NATURAL LEFT OUTER JOIN ..." であった場合、join_type は "NATURAL LEFT OUTER"
     /// であり、これをキーワードとする Clause を返す。
+    /// 設定により OUTER、INNER を除去した結果キーワードが残らない場合 (INNER JOIN) は None を返す。
     fn visit_join_type(
         &mut self,
         cursor: &mut TreeCursor,
         src: &str,
-    ) -> Result<Clause, UroboroSQLFmtError> {
+    ) -> Result<Option<Clause>, UroboroSQLFmtError> {
         cursor.goto_first_child();
 
         if !matches!(
@@ -116,9 +144,32 @@ impl Visitor {
                 )));
         }
 
-        let mut clause = create_clause(cursor, src, cursor.node().kind())?;
+        let (remove_outer_keyword, remove_inner_keyword) = {
+            let config = CONFIG.read().unwrap();
+            (config.remove_outer_keyword, config.remove_inner_keyword)
+        };
+
+        let mut clause: Option<Clause> = None;
+
+        loop {
+            // 設定に従い、省略可能な OUTER、INNER を除去する
+            //  LEFT OUTER JOIN ->  LEFT JOIN
+            //  INNER JOIN      ->  JOIN
+            let kind = cursor.node().kind();
+            let is_removed = (remove_outer_keyword && kind == "OUTER")
+                || (remove_inner_keyword && kind == "INNER");
+
+            if !is_removed {
+                match &mut clause {
+                    Some(clause) => clause.extend_kw(cursor.node(), src),
+                    None => clause = Some(create_clause(cursor, src, kind)?),
+                }
+            }
+
+            if !cursor.goto_next_sibling() {
+                break;
+            }
 
-        while cursor.goto_next_sibling() {
             if !matches!(
                 cursor.node().kind(),
                 "INNER" | "OUTER" | "LEFT" | "RIGHT" | "FULL"
@@ -127,22 +178,30 @@ impl Visitor {
                             "visit_join_type(): expected node is INNER, OUTER, LEFT, RIGHT or FULL, but actual {}\n{}", cursor.node().kind(), error_annotation_from_cursor(cursor, src)
                         )));
             }
-            clause.extend_kw(cursor.node(), src);
         }
 
         // 省略可能であるOUTERを明示的に記載する
         //  LEFT JOIN   ->  LEFT OUTER JOIN
         //  RIGHT JOIN  ->  RIGHT OUTER JOIN
         //  FULL JOIN   ->  FULL OUTER JOIN
-        if CONFIG.read().unwrap().complement_outer_keyword
-            && (clause.keyword().eq_ignore_ascii_case("LEFT")
-                || clause.keyword().eq_ignore_ascii_case("RIGHT")
-                || clause.keyword().eq_ignore_ascii_case("FULL"))
-        {
-            // keyword_case = "preserve" のとき、コーディング規約に従い大文字になる。
-            // keyword_case = "lower" のとき、extend_kw_with_string() で小文字に変換される
-            // ため、ここでは大文字で与えてよい。
-            clause.extend_kw_with_string("OUTER");
+        // OUTERを除去する設定の場合は補完しない
+        if let Some(clause) = &mut clause {
+            if CONFIG.read().unwrap().complement_outer_keyword
+                && !remove_outer_keyword
+                && (clause.keyword().eq_ignore_ascii_case("LEFT")
+                    || clause.keyword().eq_ignore_ascii_case("RIGHT")
+                    || clause.keyword().eq_ignore_ascii_case("FULL"))
+            {
+                // keyword_case = "preserve" のとき、コーディング規約に従い大文字になる。
+                // keyword_case = "lower" のとき、extend_kw_with_string() で小文字に変換される
+                // ため、ここでは大文字で与えてよい。
+                clause.extend_kw_with_string("OUTER");
+                self.add_warning(
+                    WarningKind::KeywordComplemented,
+                    "OUTER keyword was complemented",
+                    &clause.loc(),
+                );
+            }
         }
 
         cursor.goto_parent();
@@ -31,7 +31,8 @@ impl Visitor {
         let mut clause = create_clause(cursor, src, "SELECT")?;
         cursor.goto_next_sibling();
 
-        // SQL_IDとコメントを消費
+        // ヒント句、SQL_IDとコメントを消費
+        self.consume_hint(cursor, src, &mut clause);
         self.consume_or_complement_sql_id(cursor, src, &mut clause);
         self.consume_comment_in_clause(cursor, src, &mut clause)?;
 
@@ -60,19 +61,27 @@ impl Visitor {
 
                     // ( expression [, ...] ) をColumnList構造体に格納
                     let mut column_list = self.visit_column_list(cursor, src)?;
-                    // 改行によるフォーマットを強制
-                    column_list.set_force_multi_line(true);
 
-                    // ColumntListをSeparatedLinesに格納してBody
-                    let mut sep_lines = SeparatedLines::new();
+                    let body = if column_list.has_multiple_cols() {
+                        // 式が複数ある場合は改行によるフォーマットを強制
+                        column_list.set_force_multi_line(true);
 
-                    sep_lines.add_expr(
-                        Expr::ColumnList(Box::new(column_list)).to_aligned(),
-                        None,
-                        vec![],
-                    );
+                        // ColumntListをSeparatedLinesに格納してBody
+                        let mut sep_lines = SeparatedLines::new();
 
-                    distinct_clause.set_body(Body::SepLines(sep_lines));
+                        sep_lines.add_expr(
+                            Expr::ColumnList(Box::new(column_list)).to_aligned(),
+                            None,
+                            vec![],
+                        );
+
+                        Body::SepLines(sep_lines)
+                    } else {
+                        // 式が一つの場合は DISTINCT ON と同じ行に描画する
+                        Body::to_single_line(Expr::ColumnList(Box::new(column_list)))
+                    };
+
+                    distinct_clause.set_body(body);
                 }
 
                 select_body.set_all_distinct(distinct_clause);
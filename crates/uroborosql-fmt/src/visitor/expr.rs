@@ -89,14 +89,13 @@ impl Visitor {
             "boolean_expression" => self.visit_bool_expr(cursor, src)?,
             // identifier | number | string (そのまま表示)
             "identifier" | "number" | "string" => {
-                // defaultの場合はキーワードとして扱う
-                let primary = if "default"
-                    .eq_ignore_ascii_case(cursor.node().utf8_text(src.as_bytes()).unwrap())
-                {
-                    PrimaryExpr::with_node(cursor.node(), src, PrimaryExprKind::Keyword)
-                } else {
-                    PrimaryExpr::with_node(cursor.node(), src, PrimaryExprKind::Expr)
-                };
+                // default、CURRENT_TIMESTAMPなどの場合はキーワードとして扱う
+                let primary =
+                    if is_keyword_primary(cursor.node().utf8_text(src.as_bytes()).unwrap()) {
+                        PrimaryExpr::with_node(cursor.node(), src, PrimaryExprKind::Keyword)
+                    } else {
+                        PrimaryExpr::with_node(cursor.node(), src, PrimaryExprKind::Expr)
+                    };
                 Expr::Primary(Box::new(primary))
             }
             "select_subexpression" => {
@@ -153,7 +152,7 @@ impl Visitor {
 
         // バインドパラメータの追加
         if let Some(comment) = head_comment {
-            if comment.is_block_comment() && comment.loc().is_next_to(&result.loc()) {
+            if self.check_bind_param(&comment, &result.loc()) {
                 // 複数行コメントかつ式に隣接していれば、バインドパラメータ
                 result.set_head_comment(comment);
             } else {
@@ -176,3 +175,26 @@ pub(crate) fn is_comp_op(op_str: &str) -> bool {
         "<" | "<=" | "<>" | "!=" | "=" | ">" | ">=" | "~" | "!~" | "~*" | "!~*"
     )
 }
+
+/// 識別子として現れるが、キーワードとして大文字小文字変換を行う語であるかを判定する
+///
+/// DEFAULT と、括弧なしで呼び出される日付時刻関数 (CURRENT_TIMESTAMP など) が該当する。
+fn is_keyword_primary(text: &str) -> bool {
+    "default".eq_ignore_ascii_case(text) || is_datetime_function(text)
+}
+
+/// 日付時刻関数 (CURRENT_TIMESTAMP など) であるかを判定する
+///
+/// これらの関数名はキーワードであるため、`CURRENT_TIMESTAMP(3)` のように精度を指定して
+/// 関数呼び出しとして処理される場合も、関数名の設定ではなくキーワードの設定を適用する。
+fn is_datetime_function(name: &str) -> bool {
+    [
+        "current_date",
+        "current_time",
+        "current_timestamp",
+        "localtime",
+        "localtimestamp",
+    ]
+    .iter()
+    .any(|kw| kw.eq_ignore_ascii_case(name))
+}
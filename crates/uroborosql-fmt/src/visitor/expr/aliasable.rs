@@ -1,11 +1,15 @@
 use tree_sitter::TreeCursor;
 
 use crate::{
-    config::CONFIG,
+    config::{ColumnAliasNaming, CONFIG},
     cst::*,
     error::UroboroSQLFmtError,
     util::convert_keyword_case,
-    visitor::{create_alias, ensure_kind, error_annotation_from_cursor, Visitor, COMMENT},
+    visitor::{
+        create_alias, create_column_alias, ensure_kind, error_annotation_from_cursor, Visitor,
+        COMMENT,
+    },
+    warning::WarningKind,
 };
 
 /// 補完の種類
@@ -72,6 +76,16 @@ impl ComplementConfig {
             && CONFIG.read().unwrap().complement_alias
             && self.kind == ComplementKind::ColumnName
     }
+
+    /// 自身の設定と定義ファイルの設定を考慮して、識別子以外の式に補完するエイリアスの命名方法を返す
+    /// 補完すべきでない場合は None を返す
+    fn column_alias_naming(&self) -> Option<ColumnAliasNaming> {
+        if self.complement_alias && self.kind == ComplementKind::ColumnName {
+            CONFIG.read().unwrap().complement_column_alias
+        } else {
+            None
+        }
+    }
 }
 
 impl Visitor {
@@ -166,6 +180,12 @@ impl Visitor {
                     } else {
                         // ASキーワードが存在しない場合
                         if complement_config.complement_as_keyword() {
+                            // cursor -> identifier
+                            self.add_warning(
+                                WarningKind::KeywordComplemented,
+                                "AS keyword was complemented",
+                                &Location::new(cursor.node().range()),
+                            );
                             Some(convert_keyword_case("AS"))
                         } else {
                             None
@@ -199,11 +219,26 @@ impl Visitor {
 
                 let mut aligned = AlignedExpr::new(expr.clone());
 
-                if complement_config.complement_alias() {
-                    // エイリアス名を生成できた場合にエイリアス補完を行う
-                    if let Some(alias_name) = create_alias(&expr) {
-                        aligned.add_rhs(Some(convert_keyword_case("AS")), alias_name);
-                    }
+                let alias_name = if complement_config.complement_alias() {
+                    create_alias(&expr)
+                } else {
+                    None
+                }
+                .or_else(|| {
+                    // 識別子以外の式は、設定された命名方法でエイリアス名を生成する
+                    complement_config
+                        .column_alias_naming()
+                        .and_then(|naming| create_column_alias(&expr, naming))
+                });
+
+                // エイリアス名を生成できた場合にエイリアス補完を行う
+                if let Some(alias_name) = alias_name {
+                    self.add_warning(
+                        WarningKind::KeywordComplemented,
+                        "alias was complemented",
+                        &expr.loc(),
+                    );
+                    aligned.add_rhs(Some(convert_keyword_case("AS")), alias_name);
                 }
 
                 Ok(aligned)
@@ -44,7 +44,7 @@ impl Visitor {
         //```
         // 開き括弧の後のコメントのうち最後のもの（最初の式の直前にあるもの）を取得
         if let Some(comment) = start_comments.last() {
-            if comment.is_block_comment() && comment.loc().is_next_to(&first_expr.loc()) {
+            if self.check_bind_param(comment, &first_expr.loc()) {
                 // ブロックコメントかつ式に隣接していればバインドパラメータなので、式に付与する
                 first_expr.set_head_comment(comment.clone());
                 // start_comments からも削除
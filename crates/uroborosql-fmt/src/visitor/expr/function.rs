@@ -5,10 +5,12 @@ use tree_sitter::TreeCursor;
 use crate::{
     cst::*,
     error::UroboroSQLFmtError,
-    util::convert_keyword_case,
+    util::{convert_function_case, convert_keyword_case},
     visitor::{create_clause, ensure_kind, error_annotation_from_cursor, Visitor, COMMA, COMMENT},
 };
 
+use super::is_datetime_function;
+
 impl Visitor {
     pub(crate) fn visit_function_call(
         &mut self,
@@ -21,7 +23,12 @@ impl Visitor {
         // "LATERAL"は未対応
 
         // 関数名
-        let function_name = convert_keyword_case(cursor.node().utf8_text(src.as_bytes()).unwrap());
+        let function_name = cursor.node().utf8_text(src.as_bytes()).unwrap();
+        let function_name = if is_datetime_function(function_name) {
+            convert_keyword_case(function_name)
+        } else {
+            convert_function_case(function_name)
+        };
         cursor.goto_next_sibling();
 
         ensure_kind(cursor, "(", src)?;
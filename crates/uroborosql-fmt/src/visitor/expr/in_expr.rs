@@ -3,7 +3,7 @@ use tree_sitter::TreeCursor;
 use crate::{
     cst::*,
     error::UroboroSQLFmtError,
-    util::convert_keyword_case,
+    util::{convert_keyword_case, is_in_list_overflow},
     visitor::{ensure_kind, error_annotation_from_cursor, Visitor, COMMENT},
 };
 
@@ -55,7 +55,7 @@ impl Visitor {
         ensure_kind(cursor, "tuple", src)?;
 
         if let Some(comment) = bind_param {
-            if comment.is_block_comment() && comment.loc().is_next_to(&column_list.loc()) {
+            if self.check_bind_param(&comment, &column_list.loc()) {
                 column_list.set_head_comment(comment);
             } else {
                 return Err(UroboroSQLFmtError::UnexpectedSyntax(format!(
@@ -65,6 +65,19 @@ impl Visitor {
             }
         }
 
+        // リストの要素数、幅が定義ファイルにおける上限を超える場合は、複数行で描画する
+        if !column_list.is_multi_line() {
+            // リスト部分のみの文字数
+            let list_len = column_list.last_line_len(0);
+
+            if is_in_list_overflow(column_list.col_count(), list_len) {
+                column_list.set_force_multi_line(true);
+            }
+        }
+
+        // 行の長さは描画する列が決まるまでわからないため、描画時に max_line_length と比較する
+        column_list.set_wrap_at_max_line_length(true);
+
         let rhs = Expr::ColumnList(Box::new(column_list));
 
         let mut aligned = AlignedExpr::new(lhs);
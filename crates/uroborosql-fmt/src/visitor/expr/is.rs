@@ -21,29 +21,73 @@ impl Visitor {
 
         cursor.goto_next_sibling();
         ensure_kind(cursor, "IS", src)?;
-        let op = convert_keyword_case(cursor.node().utf8_text(src.as_bytes()).unwrap());
+        let mut op = convert_keyword_case(cursor.node().utf8_text(src.as_bytes()).unwrap());
         cursor.goto_next_sibling();
 
         // 右辺は "NOT" から始まる場合がある。
-        // TODO: tree-sitter-sql では、右辺に distinct_from が現れるケースがあり、それには対応していない。
-        let rhs = if cursor.node().kind() == "NOT" {
+        let not_keyword = if cursor.node().kind() == "NOT" {
             let not_str = convert_keyword_case(cursor.node().utf8_text(src.as_bytes()).unwrap());
-            let mut loc = Location::new(cursor.node().range());
+            let loc = Location::new(cursor.node().range());
             cursor.goto_next_sibling();
-
-            let operand = self.visit_expr(cursor, src)?;
-            loc.append(operand.loc());
-            Expr::Unary(Box::new(UnaryExpr::new(not_str, operand, loc)))
+            Some((not_str, loc))
         } else {
-            self.visit_expr(cursor, src)?
+            None
         };
 
         let mut aligned = AlignedExpr::new(lhs);
-        aligned.add_rhs(Some(op), rhs);
+
+        if cursor.node().kind() == "distinct_from" {
+            // IS [NOT] DISTINCT FROM の場合、"IS [NOT] DISTINCT FROM" 全体を演算子として縦揃えする
+            if let Some((not_str, _)) = not_keyword {
+                op.push(' ');
+                op.push_str(&not_str);
+            }
+
+            let rhs = self.visit_distinct_from(cursor, src, &mut op)?;
+            aligned.add_rhs(Some(op), rhs);
+        } else {
+            let rhs = match not_keyword {
+                Some((not_str, mut loc)) => {
+                    let operand = self.visit_expr(cursor, src)?;
+                    loc.append(operand.loc());
+                    Expr::Unary(Box::new(UnaryExpr::new(not_str, operand, loc)))
+                }
+                None => self.visit_expr(cursor, src)?,
+            };
+            aligned.add_rhs(Some(op), rhs);
+        }
 
         cursor.goto_parent();
         ensure_kind(cursor, "is_expression", src)?;
 
         Ok(aligned)
     }
+
+    /// distinct_from のフォーマットを行う。
+    /// distinct_from は "DISTINCT FROM" _expression という構造をしている。
+    /// キーワードを引数の演算子 op に追加し、右辺の式を返す。
+    fn visit_distinct_from(
+        &mut self,
+        cursor: &mut TreeCursor,
+        src: &str,
+        op: &mut String,
+    ) -> Result<Expr, UroboroSQLFmtError> {
+        cursor.goto_first_child();
+
+        // キーワード (DISTINCT, FROM) は名前のないノードとして現れる
+        while !cursor.node().is_named() {
+            op.push(' ');
+            op.push_str(&convert_keyword_case(
+                cursor.node().utf8_text(src.as_bytes()).unwrap(),
+            ));
+            cursor.goto_next_sibling();
+        }
+
+        let rhs = self.visit_expr(cursor, src)?;
+
+        cursor.goto_parent();
+        ensure_kind(cursor, "distinct_from", src)?;
+
+        Ok(rhs)
+    }
 }
@@ -39,9 +39,9 @@ impl Visitor {
             cursor.goto_next_sibling();
 
             ensure_kind(cursor, "type", src)?;
-            // 型は特殊な書き方をされていないことを想定し、ソースの文字列をそのまま PrimaryExpr に変換する。
-            // 例えば、"CHAR   ( 3    )" などのように、途中に空白を含むような特殊な書き方をした場合、フォーマット結果にもその空白が現れてしまう。
-            let type_name = PrimaryExpr::with_node(cursor.node(), src, PrimaryExprKind::Keyword);
+            // 型名は空白を正規化して PrimaryExpr に変換する。
+            // 例えば、"CHAR   ( 3    )" は "CHAR(3)" となる。
+            let type_name = PrimaryExpr::with_node(cursor.node(), src, PrimaryExprKind::Type);
             cursor.goto_next_sibling();
 
             ensure_kind(cursor, ")", src)?;
@@ -71,7 +71,7 @@ impl Visitor {
 
             cursor.goto_next_sibling();
 
-            let type_name = PrimaryExpr::with_node(cursor.node(), src, PrimaryExprKind::Keyword);
+            let type_name = PrimaryExpr::with_node(cursor.node(), src, PrimaryExprKind::Type);
             ensure_kind(cursor, "type", src)?;
 
             cursor.goto_parent();
@@ -0,0 +1,108 @@
+use std::fmt;
+
+use crate::cst::Location;
+
+/// 警告の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarningKind {
+    /// サポートしていない構文をフォーマットせずに出力した
+    UnsupportedStatement,
+    /// コメントの位置を移動した
+    CommentMoved,
+    /// キーワード、エイリアス、SQL_IDを補完した
+    KeywordComplemented,
+    /// 設定に未知のキーが含まれている
+    UnknownConfigKey,
+    /// 設定に非推奨のキーが含まれている
+    DeprecatedConfigKey,
+    /// バインドパラメータらしきコメントを正規化しなかった
+    BindParamNotNormalized,
+    /// `-- uroborosql-fmt:off` / `on` が文の途中に書かれていたため、文全体をフォーマットしなかった
+    MisplacedFormatMarker,
+}
+
+/// フォーマット時に発生した警告
+///
+/// Warning that occurred while formatting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    /// 警告の種類
+    pub kind: WarningKind,
+    /// 警告の内容
+    pub message: String,
+    /// 警告の対象となるソース上の範囲
+    pub span: Span,
+}
+
+impl Warning {
+    pub(crate) fn new(kind: WarningKind, message: impl Into<String>, span: Span) -> Warning {
+        Warning {
+            kind,
+            message: message.into(),
+            span,
+        }
+    }
+}
+
+/// ソース上の位置を持つ警告は、`行:列: 内容` の形式で表示する (行と列は 1 始まり)
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.span == Span::default() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(
+                f,
+                "{}:{}: {}",
+                self.span.start_line + 1,
+                self.span.start_col + 1,
+                self.message
+            )
+        }
+    }
+}
+
+/// ソース上の範囲
+///
+/// 行と列は 0 始まりで、列は行頭からのバイト数を表す。
+/// 設定に関する警告など、ソース上の位置を持たない警告の場合はすべて 0 となる。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Span {
+    pub start_line: usize,
+    pub start_col: usize,
+    pub end_line: usize,
+    pub end_col: usize,
+}
+
+impl Span {
+    /// Location から Span を生成する
+    pub(crate) fn from_location(loc: &Location) -> Span {
+        Span {
+            start_line: loc.start_position.row,
+            start_col: loc.start_position.col,
+            end_line: loc.end_position.row,
+            end_col: loc.end_position.col,
+        }
+    }
+
+    /// ソース中のバイト位置の範囲から Span を生成する
+    pub(crate) fn from_byte_range(src: &str, start: usize, end: usize) -> Span {
+        let (start_line, start_col) = line_col(src, start);
+        let (end_line, end_col) = line_col(src, end);
+
+        Span {
+            start_line,
+            start_col,
+            end_line,
+            end_col,
+        }
+    }
+}
+
+/// バイト位置を行と列に変換する
+fn line_col(src: &str, pos: usize) -> (usize, usize) {
+    let before = &src[..pos];
+    let line = before.matches('\n').count();
+    let col = before.rfind('\n').map_or(pos, |i| pos - i - 1);
+
+    (line, col)
+}
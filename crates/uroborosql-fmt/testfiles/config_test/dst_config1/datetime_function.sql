@@ -0,0 +1,6 @@
+DELETE /* _SQL_ID_ */
+FROM
+	LOGS
+WHERE
+	CREATED_AT	<	CURRENT_TIMESTAMP
+;
@@ -0,0 +1,36 @@
+SELECT
+  *
+FROM
+  t1
+JOIN
+  t2
+ON
+  t1.num  = t2.num
+;
+SELECT
+  *
+FROM
+  t1
+LEFT JOIN
+  t2
+ON
+  t1.num  = t2.num
+;
+SELECT
+  *
+FROM
+  t1
+RIGHT JOIN
+  t2
+ON
+  t1.num  = t2.num
+;
+SELECT
+  *
+FROM
+  t1
+FULL JOIN
+  t2
+ON
+  t1.num  = t2.num
+;
@@ -0,0 +1,20 @@
+select
+	*
+from
+	students
+;
+DO $$
+BEGIN
+	PERFORM 1;
+END
+$$;
+-- uroborosql-fmt:off
+SELECT  name  FROM  teachers;
+-- uroborosql-fmt:on
+select
+	*
+from
+	teachers
+where
+	id	=	1
+;
@@ -0,0 +1,20 @@
+SELECT
+	*
+FROM
+	STUDENTS
+;
+DO $$
+BEGIN
+	PERFORM 1;
+END
+$$;
+-- uroborosql-fmt:off
+SELECT  name  FROM  teachers;
+-- uroborosql-fmt:on
+SELECT
+	*
+FROM
+	TEACHERS
+WHERE
+	ID	=	1
+;
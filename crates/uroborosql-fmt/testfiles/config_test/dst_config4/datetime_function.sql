@@ -0,0 +1,6 @@
+DELETE
+from
+	LOGS
+WHERE
+	CREATED_AT	<	Current_Timestamp
+;
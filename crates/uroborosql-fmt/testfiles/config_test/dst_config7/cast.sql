@@ -0,0 +1,6 @@
+SELECT
+  CAST('2023-01-01'  AS  DATE)
+, CAST(100 AS  CHAR(3))
+, CAST((1 + 2)  AS  CHAR(1))
+WHERE
+  test  = test
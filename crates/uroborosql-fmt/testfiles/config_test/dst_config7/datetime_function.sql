@@ -0,0 +1,5 @@
+DELETE
+FROM
+  logs
+WHERE
+  created_at  < CURRENT_TIMESTAMP;
@@ -0,0 +1,12 @@
+SELECT
+  *
+FROM
+  students
+WHERE
+  student_id  <>  2;
+SELECT
+  *
+FROM
+  students
+WHERE
+  student_id  !=  2;
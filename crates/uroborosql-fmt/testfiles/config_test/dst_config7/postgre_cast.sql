@@ -0,0 +1,4 @@
+SELECT
+  ''::JSONB
+FROM
+  tbl;
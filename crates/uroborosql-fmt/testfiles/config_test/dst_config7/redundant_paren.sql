@@ -0,0 +1,10 @@
+SELECT
+  a
+FROM
+  b
+WHERE
+    (((1 = 1)))
+AND (
+        ((a = b))
+    OR  (a)       = (((42)))
+    )
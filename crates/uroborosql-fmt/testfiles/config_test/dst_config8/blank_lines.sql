@@ -0,0 +1,24 @@
+SELECT
+  *
+FROM
+  students
+;
+
+DO $$
+BEGIN
+	PERFORM 1;
+END
+$$;
+
+-- uroborosql-fmt:off
+SELECT  name  FROM  teachers;
+-- uroborosql-fmt:on
+
+SELECT
+  *
+FROM
+  teachers
+
+WHERE
+  id  = 1
+;
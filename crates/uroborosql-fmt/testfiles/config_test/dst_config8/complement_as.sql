@@ -0,0 +1,5 @@
+SELECT
+  identifier    AS  id
+, student_name
+FROM
+  japanese_student_table
@@ -0,0 +1,6 @@
+DELETE
+FROM
+  logs
+WHERE
+  created_at  < CURRENT_TIMESTAMP
+;
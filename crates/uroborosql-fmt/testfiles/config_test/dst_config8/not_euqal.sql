@@ -0,0 +1,15 @@
+SELECT
+  *
+FROM
+  students
+WHERE
+  student_id  <>  2
+;
+
+SELECT
+  *
+FROM
+  students
+WHERE
+  student_id  !=  2
+;
@@ -0,0 +1,5 @@
+SELECT
+  ''::JSONB
+FROM
+  tbl
+;
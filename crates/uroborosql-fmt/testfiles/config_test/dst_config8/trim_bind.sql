@@ -0,0 +1,6 @@
+SELECT
+  identifier
+FROM
+  japanese_student_table
+WHERE
+  sbj.grade > /*grade*/50
@@ -0,0 +1,15 @@
+SELECT
+  *
+FROM
+  students
+WHERE
+    student_id                <> ALL  (
+      SELECT
+        student_id
+      FROM
+        exam_results
+      WHERE
+        student_id  IS  NOT NULL
+    )
+AND longlonglonglonglonglong  =       test
+;
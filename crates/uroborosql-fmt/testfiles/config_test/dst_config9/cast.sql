@@ -0,0 +1,6 @@
+SELECT
+  CAST('2023-01-01'  AS  date)
+, CAST(100 AS  char(3))
+, CAST((1 + 2)  AS  char(1))
+WHERE
+  test  = test
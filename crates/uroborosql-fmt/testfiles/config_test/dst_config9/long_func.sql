@@ -0,0 +1,36 @@
+SELECT
+  normal_func(col1 + col2, param2)
+;
+SELECT
+  many_args_func(param1, param2, param3, param4)
+;
+SELECT
+  long_args_func(col1 + longlonglonglonglonglonglong, param2)
+;
+SELECT
+  longlonglonglonglonglonglonglonglonglonglonglong_func(
+    param1
+  , param2
+  , param3
+  )
+;
+SELECT
+  func1(
+    CASE
+      WHEN
+        z = 1
+      THEN
+        func3(param1, param2, param3, param4, param5)
+      ELSE
+        func2(
+          CASE
+            WHEN
+              z = 1
+            THEN
+              'ONE'
+            ELSE
+              func3(param1, param2, param3, param4, param5)
+          END
+        )
+    END
+  )
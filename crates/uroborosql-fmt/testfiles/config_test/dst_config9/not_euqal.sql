@@ -0,0 +1,14 @@
+SELECT
+  *
+FROM
+  students
+WHERE
+  student_id  <>  2
+;
+SELECT
+  *
+FROM
+  students
+WHERE
+  student_id  !=  2
+;
@@ -0,0 +1,13 @@
+SELECT
+  depname
+, empno
+, salary
+, rank() OVER(
+    PARTITION BY
+      depname
+    ORDER BY
+      salary  DESC
+  )
+FROM
+  empsalary
+;
@@ -0,0 +1,5 @@
+SELECT
+  ''::jsonb
+FROM
+  tbl
+;
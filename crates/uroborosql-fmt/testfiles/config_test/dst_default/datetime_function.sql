@@ -0,0 +1,6 @@
+delete
+from
+	logs
+where
+	created_at	<	current_timestamp
+;
@@ -0,0 +1 @@
+DELETE from logs WHERE created_at < Current_Timestamp;
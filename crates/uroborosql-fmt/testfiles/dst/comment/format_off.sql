@@ -0,0 +1,14 @@
+select
+	a	as	a
+from
+	tbl
+;
+-- uroborosql-fmt:off
+SELECT   B,C
+  FROM TBL2 ;
+-- uroborosql-fmt:on
+select
+	d	as	d
+from
+	tbl3
+;
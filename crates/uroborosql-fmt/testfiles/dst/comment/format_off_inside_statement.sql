@@ -0,0 +1,15 @@
+select
+	a	as	a
+from
+	tbl
+;
+SELECT
+-- uroborosql-fmt:off
+  B,C
+-- uroborosql-fmt:on
+FROM TBL2;
+select
+	d	as	d
+from
+	tbl3
+;
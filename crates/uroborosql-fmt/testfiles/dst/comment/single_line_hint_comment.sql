@@ -0,0 +1,5 @@
+select /*+ IndexScan(e emp_idx) */
+	e.id	as	id
+from
+	employees	e
+;
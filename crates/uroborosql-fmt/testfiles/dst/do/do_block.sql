@@ -0,0 +1,16 @@
+select
+	*
+from
+	users
+;
+DO $$
+BEGIN
+    RAISE NOTICE 'hello; world';
+END
+$$;
+-- after block
+update
+	users
+set
+	name	=	'x'
+;
@@ -0,0 +1,3 @@
+select
+	cast(100	as	char(3))
+,	cast(1.5	as	numeric(10, 2))
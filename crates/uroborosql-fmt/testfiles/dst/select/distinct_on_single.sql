@@ -0,0 +1,7 @@
+select
+	distinct on	(location)
+	location	as	location
+,	reported_at	as	reported_at
+from
+	weather_reports
+;
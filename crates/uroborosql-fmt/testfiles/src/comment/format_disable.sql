@@ -0,0 +1,3 @@
+-- uroborosql-fmt:disable
+SELECT   A,B
+  FROM TBL ;
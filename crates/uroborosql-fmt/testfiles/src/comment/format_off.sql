@@ -0,0 +1,6 @@
+SELECT A FROM TBL;
+-- uroborosql-fmt:off
+SELECT   B,C
+  FROM TBL2 ;
+-- uroborosql-fmt:on
+SELECT D FROM TBL3;
@@ -0,0 +1,7 @@
+SELECT A FROM TBL;
+SELECT
+-- uroborosql-fmt:off
+  B,C
+-- uroborosql-fmt:on
+FROM TBL2;
+SELECT D FROM TBL3;
@@ -0,0 +1,2 @@
+select /*+ IndexScan(e emp_idx) */ e.id
+from employees e;
\ No newline at end of file
@@ -0,0 +1,8 @@
+SELECT * FROM users;
+DO $$
+BEGIN
+    RAISE NOTICE 'hello; world';
+END
+$$;
+-- after block
+UPDATE users SET name = 'x';
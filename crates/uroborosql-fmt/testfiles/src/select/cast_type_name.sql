@@ -0,0 +1,3 @@
+SELECT
+  CAST(100 AS CHAR   ( 3    ))
+, CAST(1.5 AS NUMERIC ( 10 ,2 ))
@@ -0,0 +1,2 @@
+SELECT DISTINCT ON (location) location, reported_at
+FROM weather_reports;
@@ -0,0 +1,4 @@
+select col1
+from tab
+where tab.col5 = E'Escape\tString'
+and tab.col6 = U&'D\0061T\+000061'
\ No newline at end of file
@@ -0,0 +1 @@
+select id from t where a is distinct from b and c is not distinct from 1 and e is null;
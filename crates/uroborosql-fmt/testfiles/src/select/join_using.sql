@@ -0,0 +1,5 @@
+select * from t1 inner join t2 using (num);
+select * from t1 left join t2 using (num, name) where t1.num = 1;
+select * from t1 inner join t2 -- tbl
+using (num) -- cond
+;
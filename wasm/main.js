@@ -236,10 +236,17 @@ function initialize() {
     // Rust側で確保したメモリのポインタを取得
     const result_ptr = ccall("get_result_address", "number", [], []);
     const error_ptr = ccall("get_error_msg_address", "number", [], []);
+    const warnings_ptr = ccall("get_warnings_address", "number", [], []);
 
     // Module.UTF8ToString() でポインタを js の string に変換
     const res = UTF8ToString(result_ptr);
     const err = UTF8ToString(error_ptr);
+    const warnings = UTF8ToString(warnings_ptr);
+
+    // 警告はコンソールに出力する
+    if (warnings !== "") {
+      console.warn(warnings);
+    }
 
     dst_editor.setValue(res);
     document.getElementById("error_msg").innerText = err;